@@ -0,0 +1,163 @@
+use common::Error;
+use common::files::InputSource;
+use common::solution::Solution;
+use serde_json::json;
+use std::fmt::{self, Display, Formatter};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+pub type Bencher = fn(&InputSource, &[u8], usize) -> Result<Vec<Timing>, Error>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part1 => write!(f, "part1"),
+            Stage::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Timing {
+    pub day: u8,
+    pub stage: Stage,
+    samples: Vec<Duration>,
+}
+
+impl Timing {
+    fn new(day: u8, stage: Stage, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        Timing { day, stage, samples }
+    }
+
+    pub fn iterations(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        let mid = self.samples.len() / 2;
+
+        if self.samples.len().is_multiple_of(2) {
+            (self.samples[mid - 1] + self.samples[mid]) / 2
+        } else {
+            self.samples[mid]
+        }
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+}
+
+fn sample<T, F: FnMut() -> T>(iterations: usize, mut f: F) -> Vec<Duration> {
+    (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect()
+}
+
+/// Times parsing and each requested part of `S` separately, `iterations` times each.
+pub fn measure<S: Solution>(source: &InputSource, parts: &[u8], iterations: usize) -> Result<Vec<Timing>, Error> {
+    let raw = source.read_to_string()?;
+    let input = S::parse(&raw).map_err(|e| e.in_file(&source.name()))?;
+
+    let mut timings = vec![Timing::new(S::DAY, Stage::Parse, sample(iterations, || S::parse(black_box(&raw))))];
+
+    for &part in parts {
+        let timing = match part {
+            1 => Timing::new(S::DAY, Stage::Part1, sample(iterations, || S::part1(black_box(&input)))),
+            _ => Timing::new(S::DAY, Stage::Part2, sample(iterations, || S::part2(black_box(&input)))),
+        };
+
+        timings.push(timing);
+    }
+
+    Ok(timings)
+}
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+pub fn to_table(timings: &[Timing]) -> String {
+    let mut result = format!("{:<6} {:<6} {:>10} {:>12} {:>12} {:>12}\n", "day", "stage", "iterations", "min ms", "median ms", "max ms");
+
+    for t in timings {
+        result.push_str(&format!(
+            "day{:02}  {:<6} {:>10} {:>12.3} {:>12.3} {:>12.3}\n",
+            t.day, t.stage, t.iterations(), millis(t.min()), millis(t.median()), millis(t.max())
+        ));
+    }
+
+    result
+}
+
+pub fn to_csv(timings: &[Timing]) -> String {
+    let mut result = "day,stage,iterations,min_ms,median_ms,max_ms\n".to_string();
+
+    for t in timings {
+        result.push_str(&format!(
+            "{},{},{},{:.6},{:.6},{:.6}\n",
+            t.day, t.stage, t.iterations(), millis(t.min()), millis(t.median()), millis(t.max())
+        ));
+    }
+
+    result
+}
+
+pub fn to_json(timings: &[Timing]) -> String {
+    let records: Vec<_> = timings.iter()
+        .map(|t| json!({
+            "day": t.day,
+            "stage": t.stage.to_string(),
+            "iterations": t.iterations(),
+            "min_ms": millis(t.min()),
+            "median_ms": millis(t.median()),
+            "max_ms": millis(t.max()),
+        }))
+        .collect();
+
+    serde_json::to_string_pretty(&records).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(samples: &[u64]) -> Timing {
+        Timing::new(3, Stage::Part1, samples.iter().map(|&ms| Duration::from_millis(ms)).collect())
+    }
+
+    #[test]
+    fn test_timing_stats() {
+        let t = timing(&[5, 1, 3]);
+        assert_eq!((t.min(), t.median(), t.max()), (Duration::from_millis(1), Duration::from_millis(3), Duration::from_millis(5)));
+
+        let t = timing(&[4, 1, 2, 8]);
+        assert_eq!(t.median(), Duration::from_millis(3));
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&[timing(&[1, 2, 3])]),
+            "day,stage,iterations,min_ms,median_ms,max_ms\n3,part1,3,1.000000,2.000000,3.000000\n"
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use crate::bench::{self, Bencher};
+use common::Error;
+use common::files::InputSource;
+use common::solution::{self, Answer, Solution};
+use std::time::{Duration, Instant};
+
+pub type Solver = fn(&InputSource, &[u8]) -> Result<Vec<PartResult>, Error>;
+
+#[derive(Debug)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+pub struct Day {
+    pub number: u8,
+    pub solve: Solver,
+    pub bench: Bencher,
+}
+
+pub const DAYS: &[Day] = &[
+    day::<day01::Day01>(),
+    day::<day02::Day02>(),
+    day::<day03::Day03>(),
+    day::<day04::Day04>(),
+    day::<day05::Day05>(),
+    day::<day06::Day06>(),
+    day::<day07::Day07>(),
+    day::<day08::Day08>(),
+    day::<day09::Day09>(),
+];
+
+const fn day<S: Solution>() -> Day {
+    Day { number: S::DAY, solve: solve::<S>, bench: bench::measure::<S> }
+}
+
+fn solve<S: Solution>(source: &InputSource, parts: &[u8]) -> Result<Vec<PartResult>, Error> {
+    let input = source.parse_with(S::parse)?;
+
+    let results = parts.iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&input),
+                _ => S::part2(&input),
+            };
+
+            PartResult { part, answer, elapsed: start.elapsed() }
+        })
+        .collect();
+
+    Ok(results)
+}
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
+
+impl Day {
+    pub fn input_source(&self, input_override: Option<&str>) -> InputSource {
+        InputSource::resolve(&solution::input_file_name(self.number), input_override)
+    }
+
+    pub fn run(&self, source: &InputSource, parts: &[u8]) -> Result<Vec<PartResult>, Error> {
+        (self.solve)(source, parts)
+    }
+}
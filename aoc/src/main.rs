@@ -0,0 +1,160 @@
+mod bench;
+mod days;
+mod options;
+mod output;
+mod verify;
+
+use options::{Command, Format, Options, USAGE};
+use std::env;
+use std::path::PathBuf;
+use std::process;
+use verify::{Answers, Outcome};
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let options = match Options::from_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let success = match options.command {
+        Command::Run => run(&options),
+        Command::Verify => verify(&options),
+        Command::Bench => bench(&options),
+    };
+
+    if !success {
+        process::exit(1);
+    }
+}
+
+fn find_day(number: u8) -> Option<&'static days::Day> {
+    let day = days::find(number);
+
+    if day.is_none() {
+        eprintln!("day{:02}: not implemented", number);
+    }
+
+    day
+}
+
+fn run(options: &Options) -> bool {
+    let mut success = true;
+    let mut records = Vec::new();
+
+    for day in options.days.iter().filter_map(|&n| find_day(n)) {
+        let source = day.input_source(options.input.as_deref());
+
+        match day.run(&source, &options.parts()) {
+            Ok(results) => {
+                for result in results {
+                    match options.format {
+                        Format::Json => records.push(output::to_record(day.number, &result)),
+                        _ => println!("day{:02} part{}: {}", day.number, result.part, result.answer),
+                    }
+                }
+            },
+            Err(e) => {
+                eprintln!("day{:02}: {}", day.number, e);
+                success = false;
+            },
+        }
+    }
+
+    if options.format == Format::Json {
+        println!("{}", serde_json::to_string_pretty(&records).unwrap());
+    }
+
+    success
+}
+
+/// Checks each part against the recorded answers. Parts without a recorded
+/// answer are reported as missing without being run.
+fn verify(options: &Options) -> bool {
+    let path = options.answers.as_ref().map_or_else(Answers::default_path, PathBuf::from);
+
+    let answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for day in options.days.iter().filter_map(|&n| find_day(n)) {
+        let parts: Vec<u8> = options.parts().into_iter()
+            .filter(|&part| answers.expected(day.number, part).is_some())
+            .collect();
+
+        let results = if parts.is_empty() {
+            Vec::new()
+        } else {
+            match day.run(&day.input_source(None), &parts) {
+                Ok(results) => results,
+                Err(e) => {
+                    println!("day{:02}: error: {}", day.number, e);
+                    failed += parts.len();
+                    continue;
+                }
+            }
+        };
+
+        for part in options.parts() {
+            let outcome = match results.iter().find(|r| r.part == part) {
+                Some(result) => verify::check(answers.expected(day.number, part).as_deref(), &result.answer),
+                None => Outcome::Missing,
+            };
+
+            match outcome {
+                Outcome::Pass => {
+                    println!("day{:02} part{}: pass", day.number, part);
+                    passed += 1;
+                },
+                Outcome::Fail { expected, actual } => {
+                    println!("day{:02} part{}: FAIL", day.number, part);
+                    for line in verify::diff(&expected, &actual) {
+                        println!("    {}", line);
+                    }
+                    failed += 1;
+                },
+                Outcome::Missing => {
+                    println!("day{:02} part{}: missing", day.number, part);
+                    missing += 1;
+                },
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+
+    failed == 0
+}
+
+fn bench(options: &Options) -> bool {
+    let mut success = true;
+    let mut timings = Vec::new();
+
+    for day in options.days.iter().filter_map(|&n| find_day(n)) {
+        match (day.bench)(&day.input_source(None), &options.parts(), options.iterations) {
+            Ok(t) => timings.extend(t),
+            Err(e) => {
+                eprintln!("day{:02}: {}", day.number, e);
+                success = false;
+            },
+        }
+    }
+
+    match options.format {
+        Format::Text => print!("{}", bench::to_table(&timings)),
+        Format::Json => println!("{}", bench::to_json(&timings)),
+        Format::Csv => print!("{}", bench::to_csv(&timings)),
+    }
+
+    success
+}
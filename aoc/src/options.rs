@@ -0,0 +1,192 @@
+use crate::days;
+
+pub const USAGE: &str = "\
+usage: aoc run [all | <day> | <from>-<to>]... [--part <1|2>] [--input <path> | -] [--format <text|json>]
+       aoc verify [all | <day> | <from>-<to>]... [--part <1|2>] [--answers <path>]
+       aoc bench [all | <day> | <from>-<to>]... [--part <1|2>] [--iterations <n>] [--format <text|json|csv>]";
+
+pub const DEFAULT_ITERATIONS: usize = 10;
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run,
+    Verify,
+    Bench,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Options {
+    pub command: Command,
+    pub days: Vec<u8>,
+    pub part: Option<u8>,
+    pub input: Option<String>,
+    pub answers: Option<String>,
+    pub iterations: usize,
+    pub format: Format,
+}
+
+impl Options {
+    pub fn from_args(args: &[String]) -> Result<Options, String> {
+        let mut args = args.iter();
+
+        let command = match args.next().map(|s| s.as_str()) {
+            Some("run") => Command::Run,
+            Some("verify") => Command::Verify,
+            Some("bench") => Command::Bench,
+            Some(x) => return Err(format!("Invalid command \"{}\" specified.", x)),
+            None => return Err("No command specified.".to_string()),
+        };
+
+        let mut days = Vec::new();
+        let mut part = None;
+        let mut input = None;
+        let mut answers = None;
+        let mut iterations = DEFAULT_ITERATIONS;
+        let mut format = Format::Text;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" => {
+                    part = match args.next().map(|s| s.as_str()) {
+                        Some("1") => Some(1),
+                        Some("2") => Some(2),
+                        Some(x) => return Err(format!("Invalid part \"{}\" specified.", x)),
+                        None => return Err("No part specified after --part.".to_string()),
+                    };
+                },
+                "--input" => {
+                    input = match args.next() {
+                        Some(path) => Some(path.clone()),
+                        None => return Err("No path specified after --input.".to_string()),
+                    };
+                },
+                "--answers" => {
+                    answers = match args.next() {
+                        Some(path) => Some(path.clone()),
+                        None => return Err("No path specified after --answers.".to_string()),
+                    };
+                },
+                "--iterations" => {
+                    iterations = match args.next().map(|s| s.parse::<usize>()) {
+                        Some(Ok(n)) if n > 0 => n,
+                        Some(_) => return Err("Invalid number of iterations specified.".to_string()),
+                        None => return Err("No number specified after --iterations.".to_string()),
+                    };
+                },
+                "--format" => {
+                    format = match args.next().map(|s| s.as_str()) {
+                        Some("text") => Format::Text,
+                        Some("json") => Format::Json,
+                        Some("csv") => Format::Csv,
+                        Some(x) => return Err(format!("Invalid format \"{}\" specified.", x)),
+                        None => return Err("No format specified after --format.".to_string()),
+                    };
+                },
+                "all" => days.extend(days::DAYS.iter().map(|d| d.number)),
+                x => days.extend(parse_days(x)?),
+            }
+        }
+
+        if days.is_empty() {
+            days.extend(days::DAYS.iter().map(|d| d.number));
+        }
+
+        if input.is_some() && command != Command::Run {
+            return Err("--input is only supported by run.".to_string());
+        }
+
+        if input.is_some() && days.len() != 1 {
+            return Err("--input can only be used with a single day.".to_string());
+        }
+
+        match (&command, &format) {
+            (Command::Verify, Format::Json) | (Command::Verify, Format::Csv) => {
+                return Err("--format is not supported by verify.".to_string());
+            },
+            (Command::Run, Format::Csv) => return Err("run only supports text and json output.".to_string()),
+            _ => {},
+        }
+
+        Ok(Options { command, days, part, input, answers, iterations, format })
+    }
+
+    pub fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+fn parse_days(s: &str) -> Result<Vec<u8>, String> {
+    let parse_day = |d: &str| d.parse::<u8>()
+        .map_err(|_| format!("Invalid day \"{}\" specified.", d));
+
+    match s.find('-') {
+        Some(i) => {
+            let from = parse_day(&s[..i])?;
+            let to = parse_day(&s[i + 1..])?;
+
+            if from > to {
+                return Err(format!("Invalid day range \"{}\" specified.", s));
+            }
+
+            Ok((from..=to).collect())
+        },
+        None => Ok(vec![parse_day(s)?]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(|a| a.to_string()).collect()
+    }
+
+    fn run(days: Vec<u8>, part: Option<u8>, input: Option<&str>) -> Options {
+        Options {
+            command: Command::Run,
+            days,
+            part,
+            input: input.map(|s| s.to_string()),
+            answers: None,
+            iterations: DEFAULT_ITERATIONS,
+            format: Format::Text,
+        }
+    }
+
+    #[test]
+    fn test_options_from_args() {
+        assert_eq!(Options::from_args(&args("run 4 --part 2")), Ok(run(vec![4], Some(2), None)));
+        assert_eq!(Options::from_args(&args("run 2-4 7")), Ok(run(vec![2, 3, 4, 7], None, None)));
+        assert_eq!(Options::from_args(&args("run")), Ok(run((1..=9).collect(), None, None)));
+        assert_eq!(Options::from_args(&args("run 6 --input -")), Ok(run(vec![6], None, Some("-"))));
+        assert_eq!(
+            Options::from_args(&args("verify 8 --answers a.toml")).map(|o| (o.command, o.answers)),
+            Ok((Command::Verify, Some("a.toml".to_string())))
+        );
+        assert_eq!(
+            Options::from_args(&args("bench 5 --iterations 3 --format csv")).map(|o| (o.command, o.iterations, o.format)),
+            Ok((Command::Bench, 3, Format::Csv))
+        );
+        assert!(Options::from_args(&args("bench 5 --iterations 0")).is_err());
+        assert_eq!(Options::from_args(&args("run 5 --format json")).map(|o| o.format), Ok(Format::Json));
+        assert!(Options::from_args(&args("run 5 --format csv")).is_err());
+        assert!(Options::from_args(&args("verify 5 --format json")).is_err());
+        assert!(Options::from_args(&args("run 1-2 --input day01.txt")).is_err());
+        assert!(Options::from_args(&args("bench 5 --input other.txt")).is_err());
+        assert!(Options::from_args(&args("verify 5 --input other.txt")).is_err());
+        assert!(Options::from_args(&args("run 4 --part 3")).is_err());
+        assert!(Options::from_args(&args("run 5-2")).is_err());
+        assert!(Options::from_args(&args("walk 1")).is_err());
+    }
+}
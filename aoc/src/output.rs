@@ -0,0 +1,56 @@
+use crate::days::PartResult;
+use common::solution::Answer;
+use serde_json::{json, Value};
+
+/// A `{day, part, answer, elapsed_ms}` record for one solved part. Grid
+/// answers use the decoded letters as the answer and add the raw `pixels` rows.
+pub fn to_record(day: u8, result: &PartResult) -> Value {
+    let answer = match &result.answer {
+        Answer::Number(n) => json!(n),
+        Answer::Text(s) => json!(s),
+        Answer::Grid(_) => json!(result.answer.decoded()),
+        Answer::Unsolved => Value::Null,
+    };
+
+    let mut record = json!({
+        "day": day,
+        "part": result.part,
+        "answer": answer,
+        "elapsed_ms": result.elapsed.as_secs_f64() * 1000.0,
+    });
+
+    if let Answer::Grid(grid) = &result.answer {
+        let pixels: Vec<String> = grid.rows()
+            .map(|row| row.iter().map(|&lit| if lit { '#' } else { '.' }).collect())
+            .collect();
+
+        record["pixels"] = json!(pixels);
+    }
+
+    record
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::grid::Grid;
+    use std::time::Duration;
+
+    #[test]
+    fn test_to_record() {
+        let result = PartResult { part: 1, answer: Answer::Number(332), elapsed: Duration::from_millis(2) };
+
+        assert_eq!(to_record(1, &result), json!({ "day": 1, "part": 1, "answer": 332, "elapsed_ms": 2.0 }));
+
+        let result = PartResult {
+            part: 2,
+            answer: Answer::Grid(Grid::from_rows(vec![vec![true, false], vec![false, true]]).unwrap()),
+            elapsed: Duration::from_millis(0),
+        };
+
+        assert_eq!(
+            to_record(8, &result),
+            json!({ "day": 8, "part": 2, "answer": null, "elapsed_ms": 0.0, "pixels": ["#.", ".#"] })
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use common::files;
+use common::solution::Answer;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::Value;
+
+/// The accepted answers recorded in `answers.toml`, keyed by `dayNN` tables
+/// with optional `part1`/`part2` entries.
+pub struct Answers {
+    table: toml::value::Table,
+}
+
+impl Answers {
+    pub fn default_path() -> PathBuf {
+        files::input_dir().join("answers.toml")
+    }
+
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        let s = fs::read_to_string(path)
+            .map_err(|e| format!("couldn't read {}: {}", path.display(), e))?;
+
+        Answers::from_toml(&s).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn from_toml(s: &str) -> Result<Answers, String> {
+        match s.parse::<Value>().map_err(|e| e.to_string())? {
+            Value::Table(table) => Ok(Answers { table }),
+            _ => Err("expected a table of days".to_string()),
+        }
+    }
+
+    pub fn expected(&self, day: u8, part: u8) -> Option<String> {
+        let value = self.table.get(&format!("day{:02}", day))?.get(format!("part{}", part))?;
+
+        match value {
+            Value::String(s) => Some(s.clone()),
+            v => Some(v.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing,
+}
+
+pub fn check(expected: Option<&str>, actual: &Answer) -> Outcome {
+    let expected = match expected {
+        Some(expected) => normalize(expected),
+        None => return Outcome::Missing,
+    };
+    if actual.decoded().as_deref() == Some(expected.as_str()) {
+        return Outcome::Pass;
+    }
+
+    let actual = normalize(&actual.to_plain_string());
+
+    if expected == actual {
+        Outcome::Pass
+    } else {
+        Outcome::Fail { expected, actual }
+    }
+}
+
+/// Ignores trailing whitespace and surrounding blank lines, so multi-line
+/// answers survive being pasted into a TOML string.
+fn normalize(s: &str) -> String {
+    let lines: Vec<&str> = s.lines().map(|l| l.trim_end()).collect();
+
+    lines.join("\n").trim_matches('\n').to_string()
+}
+
+/// Renders a failure as `expected`/`actual` for single lines, or as a
+/// line-by-line `-`/`+` diff of the lines that differ for multi-line answers.
+pub fn diff(expected: &str, actual: &str) -> Vec<String> {
+    if !expected.contains('\n') && !actual.contains('\n') {
+        return vec![format!("expected: {}", expected), format!("actual:   {}", actual)];
+    }
+
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut results = Vec::new();
+
+    for i in 0..expected.len().max(actual.len()) {
+        let e = expected.get(i);
+        let a = actual.get(i);
+
+        if e != a {
+            if let Some(e) = e {
+                results.push(format!("{:>3} - {}", i + 1, e));
+            }
+            if let Some(a) = a {
+                results.push(format!("{:>3} + {}", i + 1, a));
+            }
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::grid::Grid;
+
+    #[test]
+    fn test_expected() {
+        let answers = Answers::from_toml("[day01]\npart1 = 332\n\n[day02]\npart2 = \"9365C\"\n").unwrap();
+
+        assert_eq!(answers.expected(1, 1), Some("332".to_string()));
+        assert_eq!(answers.expected(1, 2), None);
+        assert_eq!(answers.expected(2, 2), Some("9365C".to_string()));
+        assert_eq!(answers.expected(3, 1), None);
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check(Some("332"), &Answer::Number(332)), Outcome::Pass);
+        assert_eq!(check(None, &Answer::Number(332)), Outcome::Missing);
+        assert_eq!(
+            check(Some("332"), &Answer::Number(333)),
+            Outcome::Fail { expected: "332".to_string(), actual: "333".to_string() }
+        );
+        let grid = Answer::Grid(Grid::from_rows(vec![vec![true, false], vec![false, true]]).unwrap());
+        assert_eq!(check(Some("\n# .\n. #\n"), &grid), Outcome::Pass);
+        assert!(check(Some("# .\n# #"), &grid) != Outcome::Pass);
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(diff("1", "2"), vec!["expected: 1", "actual:   2"]);
+        assert_eq!(diff("# .\n. #", "# .\n# #"), vec!["  2 - . #", "  2 + # #"]);
+    }
+}
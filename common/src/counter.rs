@@ -0,0 +1,135 @@
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+/// Counts occurrences of items. Rankings break ties by key so results are
+/// deterministic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Counter<T: Ord> {
+    counts: BTreeMap<T, usize>,
+}
+
+impl<T: Ord> Counter<T> {
+    pub fn new() -> Self {
+        Counter { counts: BTreeMap::new() }
+    }
+
+    pub fn add(&mut self, item: T) {
+        *self.counts.entry(item).or_insert(0) += 1;
+    }
+
+    pub fn count(&self, item: &T) -> usize {
+        self.counts.get(item).cloned().unwrap_or(0)
+    }
+
+    /// The number of distinct items counted.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Up to `n` items ordered by highest count, then by key.
+    pub fn most_common(&self, n: usize) -> Vec<(&T, usize)> {
+        let mut items: Vec<(&T, usize)> = self.counts.iter().map(|(k, &c)| (k, c)).collect();
+        items.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        items.truncate(n);
+
+        items
+    }
+
+    /// Up to `n` items ordered by lowest count, then by key.
+    pub fn least_common(&self, n: usize) -> Vec<(&T, usize)> {
+        let mut items: Vec<(&T, usize)> = self.counts.iter().map(|(k, &c)| (k, c)).collect();
+        items.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(b.0)));
+        items.truncate(n);
+
+        items
+    }
+}
+
+impl<T: Ord> Default for Counter<T> {
+    fn default() -> Self {
+        Counter::new()
+    }
+}
+
+impl<T: Ord> Extend<T> for Counter<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.add(item);
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Counter::new();
+        counter.extend(iter);
+
+        counter
+    }
+}
+
+/// A `Counter` per column, for rows of items read position by position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnCounter<T: Ord> {
+    columns: Vec<Counter<T>>,
+}
+
+impl<T: Ord> ColumnCounter<T> {
+    pub fn new() -> Self {
+        ColumnCounter { columns: Vec::new() }
+    }
+
+    pub fn add_row<I: IntoIterator<Item = T>>(&mut self, row: I) {
+        for (i, item) in row.into_iter().enumerate() {
+            if i == self.columns.len() {
+                self.columns.push(Counter::new());
+            }
+
+            self.columns[i].add(item);
+        }
+    }
+
+    pub fn columns(&self) -> &[Counter<T>] {
+        &self.columns
+    }
+}
+
+impl<T: Ord> Default for ColumnCounter<T> {
+    fn default() -> Self {
+        ColumnCounter::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_most_and_least_common() {
+        let counter: Counter<char> = "aaaaabbbzyx".chars().collect();
+
+        assert_eq!(counter.count(&'a'), 5);
+        assert_eq!(counter.count(&'q'), 0);
+        assert_eq!(counter.most_common(3), vec![(&'a', 5), (&'b', 3), (&'x', 1)]);
+        assert_eq!(counter.least_common(2), vec![(&'x', 1), (&'y', 1)]);
+        assert_eq!(counter.most_common(10).len(), 5);
+    }
+
+    #[test]
+    fn test_column_counter() {
+        let mut columns = ColumnCounter::new();
+        columns.add_row("ab".chars());
+        columns.add_row("cbd".chars());
+        columns.add_row("ae".chars());
+
+        let most: String = columns.columns().iter()
+            .map(|c| *c.most_common(1)[0].0)
+            .collect();
+
+        assert_eq!(most, "abd");
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum Error {
+    NotFound { path: PathBuf },
+    Io { path: PathBuf, source: io::Error },
+    InvalidUtf8 { path: PathBuf },
+    Parse { file: Option<String>, line: usize, column: usize, text: String, message: String },
+}
+
+impl Error {
+    pub(crate) fn from_io(path: PathBuf, source: io::Error) -> Self {
+        match source.kind() {
+            io::ErrorKind::NotFound => Error::NotFound { path },
+            io::ErrorKind::InvalidData => Error::InvalidUtf8 { path },
+            _ => Error::Io { path, source },
+        }
+    }
+
+    /// Records which file a parse error came from.
+    pub fn in_file(self, name: &str) -> Self {
+        match self {
+            Error::Parse { line, column, text, message, .. } => {
+                Error::Parse { file: Some(name.to_string()), line, column, text, message }
+            },
+            e => e,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotFound { path } => write!(f, "input missing: {}", path.display()),
+            Error::Io { path, source } => write!(f, "couldn't read {}: {}", path.display(), source),
+            Error::InvalidUtf8 { path } => write!(f, "{} is not valid UTF-8", path.display()),
+            Error::Parse { file, line, column, text, message } => {
+                if let Some(file) = file {
+                    write!(f, "{}:", file)?;
+                }
+
+                write!(f, "{}:{}: {} (in \"{}\")", line, column, message, text)
+            },
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
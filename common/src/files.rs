@@ -0,0 +1,152 @@
+use crate::Error;
+use std::env;
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a day reads its puzzle input from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Resolves `file_name` against the input directory unless an explicit
+    /// `--input` override is given, where `-` means stdin.
+    pub fn resolve(file_name: &str, input_override: Option<&str>) -> InputSource {
+        match input_override {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => InputSource::File(input_dir().join(file_name)),
+        }
+    }
+
+    /// A short name for error messages: the file name, or `-` for stdin.
+    pub fn name(&self) -> String {
+        match self {
+            InputSource::File(path) => path.file_name()
+                .map_or_else(|| path.display().to_string(), |n| n.to_string_lossy().to_string()),
+            InputSource::Stdin => "-".to_string(),
+        }
+    }
+
+    /// Reads the whole input and parses it with `parse`, tagging parse errors with the source name.
+    pub fn parse_with<T, F>(&self, parse: F) -> Result<T, Error>
+        where F: FnOnce(&str) -> Result<T, Error>
+    {
+        parse(&self.read_to_string()?).map_err(|e| e.in_file(&self.name()))
+    }
+
+    pub fn read_to_string(&self) -> Result<String, Error> {
+        match self {
+            InputSource::File(path) => {
+                fs::read_to_string(path).map_err(|e| Error::from_io(path.clone(), e))
+            },
+            InputSource::Stdin => {
+                let mut s = String::new();
+
+                io::stdin().read_to_string(&mut s)
+                    .map_err(|e| Error::from_io(PathBuf::from("-"), e))?;
+
+                Ok(s)
+            },
+        }
+    }
+}
+
+/// The directory holding `dayNN.txt` inputs: `$AOC_INPUT_DIR` if set, then
+/// `./input`, then the workspace's `input/` so binaries work from any crate directory.
+pub fn input_dir() -> PathBuf {
+    if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+        return PathBuf::from(dir);
+    }
+
+    let local = PathBuf::from("input");
+    if local.is_dir() {
+        return local;
+    }
+
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .map(|workspace| workspace.join("input"))
+        .unwrap_or(local)
+}
+
+/// Returns the value following `--input` in `args`, if any.
+pub fn input_override(args: &[String]) -> Option<&str> {
+    args.iter()
+        .position(|a| a == "--input")
+        .and_then(|i| args.get(i + 1))
+        .map(|s| s.as_str())
+}
+
+pub fn try_get_file_bytes(file_name: &str) -> Result<Vec<u8>, Error> {
+    let path = input_dir().join(file_name);
+
+    fs::read(&path).map_err(|e| Error::from_io(path, e))
+}
+
+pub fn try_get_file_lines(file_name: &str) -> Result<Vec<String>, Error> {
+    let path = input_dir().join(file_name);
+
+    let file = File::open(&path).map_err(|e| Error::from_io(path.clone(), e))?;
+
+    BufReader::new(file)
+        .lines()
+        .map(|l| l.map_err(|e| Error::from_io(path.clone(), e)))
+        .collect()
+}
+
+pub fn try_get_file_as_string(file_name: &str) -> Result<String, Error> {
+    InputSource::resolve(file_name, None).read_to_string()
+}
+
+pub fn get_file_bytes(file_name: &str) -> Vec<u8> {
+    try_get_file_bytes(file_name).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn get_file_lines(file_name: &str) -> Vec<String> {
+    try_get_file_lines(file_name).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn get_file_as_string(file_name: &str) -> String {
+    try_get_file_as_string(file_name).unwrap_or_else(|e| panic!("{}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_file() {
+        match try_get_file_lines("day00.txt") {
+            Err(Error::NotFound { path }) => assert!(path.ends_with("input/day00.txt")),
+            x => panic!("Expected NotFound, got {:?}", x),
+        }
+    }
+
+    #[test]
+    fn test_input_source_resolve() {
+        assert_eq!(InputSource::resolve("day01.txt", Some("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::resolve("day01.txt", Some("other.txt")),
+            InputSource::File(PathBuf::from("other.txt"))
+        );
+        assert_eq!(
+            InputSource::resolve("day01.txt", None),
+            InputSource::File(input_dir().join("day01.txt"))
+        );
+    }
+
+    #[test]
+    fn test_input_override() {
+        let args: Vec<String> = vec!["run".to_string(), "--input".to_string(), "-".to_string()];
+
+        assert_eq!(input_override(&args), Some("-"));
+        assert_eq!(input_override(&args[..1]), None);
+    }
+}
@@ -0,0 +1,251 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, Index, IndexMut};
+
+/// A position on a grid. `y` grows downwards, so `North` is `y - 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    pub fn step(self, direction: Direction, distance: i64) -> Self {
+        let offset = direction.offset();
+
+        Point::new(self.x + offset.x * distance, self.y + offset.y * distance)
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::South => Point::new(0, 1),
+            Direction::West => Point::new(-1, 0),
+        }
+    }
+}
+
+/// A fixed-size, row-major grid of cells.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Shifts every cell in row `y` right by `distance`, wrapping around.
+    pub fn rotate_row(&mut self, y: usize, distance: usize) {
+        if self.width > 0 {
+            self.cells[y * self.width..(y + 1) * self.width].rotate_right(distance % self.width);
+        }
+    }
+
+    /// Shifts every cell in column `x` down by `distance`, wrapping around.
+    pub fn rotate_column(&mut self, x: usize, distance: usize) {
+        if self.height == 0 {
+            return;
+        }
+
+        let mut column: Vec<T> = (0..self.height)
+            .map(|y| self.cells[y * self.width + x].clone())
+            .collect();
+        column.rotate_right(distance % self.height);
+
+        for (y, cell) in column.into_iter().enumerate() {
+            self.cells[y * self.width + x] = cell;
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from rows, or `None` if they aren't all the same width.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |r| r.len());
+
+        if rows.iter().any(|r| r.len() != width) {
+            return None;
+        }
+
+        Some(Grid { width, height, cells: rows.into_iter().flatten().collect() })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    fn index_of(&self, p: Point) -> Option<usize> {
+        if self.contains(p) {
+            Some(p.y as usize * self.width + p.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(move |i| &mut self.cells[i])
+    }
+
+    /// The point one step from `p` in `direction`, if it's on the grid.
+    pub fn neighbour(&self, p: Point, direction: Direction) -> Option<Point> {
+        Some(p.step(direction, 1)).filter(|&n| self.contains(n))
+    }
+
+    /// The on-grid points orthogonally adjacent to `p`.
+    pub fn neighbours(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL.iter().filter_map(move |&d| self.neighbour(p, d))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width as i64, self.height as i64);
+
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn map<U, F: Fn(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p).unwrap_or_else(|| panic!("{:?} is outside the grid.", p))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.get_mut(p).unwrap_or_else(|| panic!("{:?} is outside the grid.", p))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::from_rows(vec![
+            vec!['a', 'b', 'c'],
+            vec!['d', 'e', 'f'],
+        ]).unwrap()
+    }
+
+    #[test]
+    fn test_direction_turns() {
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::West.turn_right().turn_right(), Direction::East);
+        assert_eq!(Point::ORIGIN.step(Direction::West, 3), Point::new(-3, 0));
+    }
+
+    #[test]
+    fn test_get_and_neighbours() {
+        let g = grid();
+
+        assert_eq!(g.get(Point::new(2, 1)), Some(&'f'));
+        assert_eq!(g.get(Point::new(3, 0)), None);
+        assert_eq!(g.get(Point::new(0, -1)), None);
+        assert_eq!(g.neighbour(Point::new(0, 0), Direction::North), None);
+        assert_eq!(g.neighbours(Point::new(0, 0)).collect::<Vec<_>>(), vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut g = grid();
+
+        g.rotate_row(0, 1);
+        assert_eq!(g.to_string(), "cab\ndef\n");
+
+        g.rotate_column(2, 3);
+        assert_eq!(g.to_string(), "caf\ndeb\n");
+    }
+}
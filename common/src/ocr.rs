@@ -0,0 +1,76 @@
+use crate::grid::{Grid, Point};
+
+/// Letters in the 6-pixel-high font used by the puzzles' screens, each in a
+/// 5-pixel-wide cell (the last column is normally blank).
+const LETTERS: &[(char, [&str; 6])] = &[
+    ('A', [".##..", "#..#.", "#..#.", "####.", "#..#.", "#..#."]),
+    ('B', ["###..", "#..#.", "###..", "#..#.", "#..#.", "###.."]),
+    ('C', [".##..", "#..#.", "#....", "#....", "#..#.", ".##.."]),
+    ('E', ["####.", "#....", "###..", "#....", "#....", "####."]),
+    ('F', ["####.", "#....", "###..", "#....", "#....", "#...."]),
+    ('G', [".##..", "#..#.", "#....", "#.##.", "#..#.", ".###."]),
+    ('H', ["#..#.", "#..#.", "####.", "#..#.", "#..#.", "#..#."]),
+    ('I', [".###.", "..#..", "..#..", "..#..", "..#..", ".###."]),
+    ('J', ["..##.", "...#.", "...#.", "...#.", "#..#.", ".##.."]),
+    ('K', ["#..#.", "#.#..", "##...", "#.#..", "#.#..", "#..#."]),
+    ('L', ["#....", "#....", "#....", "#....", "#....", "####."]),
+    ('O', [".##..", "#..#.", "#..#.", "#..#.", "#..#.", ".##.."]),
+    ('P', ["###..", "#..#.", "#..#.", "###..", "#....", "#...."]),
+    ('R', ["###..", "#..#.", "#..#.", "###..", "#.#..", "#..#."]),
+    ('S', [".###.", "#....", "#....", ".##..", "...#.", "###.."]),
+    ('U', ["#..#.", "#..#.", "#..#.", "#..#.", "#..#.", ".##.."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####.", "...#.", "..#..", ".#...", "#....", "####."]),
+];
+
+pub const LETTER_WIDTH: usize = 5;
+pub const LETTER_HEIGHT: usize = 6;
+
+/// Reads the letters spelled out by lit pixels. Returns `None` if the grid
+/// isn't 6 pixels high or any cell isn't a known letter.
+pub fn decode(grid: &Grid<bool>) -> Option<String> {
+    if grid.height() != LETTER_HEIGHT {
+        return None;
+    }
+
+    let mut result = String::new();
+
+    for start in (0..grid.width()).step_by(LETTER_WIDTH) {
+        let cell: Vec<String> = (0..LETTER_HEIGHT)
+            .map(|y| (start..start + LETTER_WIDTH)
+                .map(|x| if grid.get(Point::new(x as i64, y as i64)) == Some(&true) { '#' } else { '.' })
+                .collect())
+            .collect();
+
+        let (c, _) = LETTERS.iter()
+            .find(|(_, glyph)| glyph.iter().zip(cell.iter()).all(|(g, c)| g == c))?;
+
+        result.push(*c);
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(s: &str) -> Grid<bool> {
+        Grid::from_rows(s.lines().map(|l| l.chars().map(|c| c == '#').collect()).collect()).unwrap()
+    }
+
+    #[test]
+    fn test_decode() {
+        let screen = grid("\
+            #..#..##..\n\
+            #..#.#..#.\n\
+            ####.#..#.\n\
+            #..#.#..#.\n\
+            #..#.#..#.\n\
+            #..#..##..");
+
+        assert_eq!(decode(&screen), Some("HO".to_string()));
+        assert_eq!(decode(&grid("#..#\n#..#")), None);
+        assert_eq!(decode(&grid("#\n#\n#\n#\n#\n#")), None);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::Error;
+use std::io::BufRead;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Why a single line failed to parse, and where in the line (1-based).
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineError {
+    pub column: usize,
+    pub message: String,
+}
+
+impl LineError {
+    pub fn new(column: usize, message: String) -> Self {
+        LineError { column, message }
+    }
+}
+
+impl From<String> for LineError {
+    fn from(message: String) -> Self {
+        LineError::new(1, message)
+    }
+}
+
+/// Parses every line of `input` as a `T`, skipping blank lines.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, Error>
+    where T: FromStr, T::Err: Into<LineError>
+{
+    parse_lines_with(input, str::parse::<T>)
+}
+
+/// Parses every line of `input` with `f`, skipping blank lines.
+pub fn parse_lines_with<T, E, F>(input: &str, f: F) -> Result<Vec<T>, Error>
+    where E: Into<LineError>, F: Fn(&str) -> Result<T, E>
+{
+    input.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| parse_item(line, i + 1, 0, line, &f))
+        .collect()
+}
+
+/// Parses `reader` a line at a time with `f`, skipping blank lines, so only
+/// one line is held in memory at once. Each item comes with its 1-based line
+/// number. Read errors are reported against `-`.
+pub fn parse_lines_from<R, T, E, F>(reader: R, f: F) -> impl Iterator<Item = Result<(usize, T), Error>>
+    where R: BufRead, E: Into<LineError>, F: Fn(&str) -> Result<T, E>
+{
+    reader.lines()
+        .enumerate()
+        .filter_map(move |(i, line)| match line {
+            Ok(line) if line.trim().is_empty() => None,
+            Ok(line) => Some(parse_item(&line, i + 1, 0, &line, &f).map(|item| (i + 1, item))),
+            Err(e) => Some(Err(Error::from_io(PathBuf::from("-"), e))),
+        })
+}
+
+/// Parses every `separator`-delimited item of every line of `input` as a `T`.
+pub fn parse_separated<T>(input: &str, separator: &str) -> Result<Vec<T>, Error>
+    where T: FromStr, T::Err: Into<LineError>
+{
+    let mut results = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let mut offset = 0;
+
+        for item in line.split(separator) {
+            let trimmed = item.trim();
+
+            if !trimmed.is_empty() {
+                let column = offset + item.find(trimmed).unwrap_or(0);
+                results.push(parse_item(trimmed, i + 1, column, line, str::parse::<T>)?);
+            }
+
+            offset += item.len() + separator.len();
+        }
+    }
+
+    Ok(results)
+}
+
+fn parse_item<T, E, F>(item: &str, line: usize, offset: usize, text: &str, f: F) -> Result<T, Error>
+    where E: Into<LineError>, F: Fn(&str) -> Result<T, E>
+{
+    f(item).map_err(|e| {
+        let e = e.into();
+
+        Error::Parse {
+            file: None,
+            line,
+            column: offset + e.column,
+            text: text.to_string(),
+            message: e.message,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Digit(u32);
+
+    impl FromStr for Digit {
+        type Err = LineError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s.chars().position(|c| !c.is_ascii_digit()) {
+                Some(i) => Err(LineError::new(i + 1, "Invalid digit".to_string())),
+                None => Ok(Digit(s.parse().unwrap())),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(parse_lines::<Digit>("1\n\n23\n").unwrap(), vec![Digit(1), Digit(23)]);
+
+        match parse_lines::<Digit>("1\n2x3") {
+            Err(Error::Parse { line, column, text, .. }) => {
+                assert_eq!((line, column, text.as_str()), (2, 2, "2x3"));
+            },
+            x => panic!("Expected Parse, got {:?}", x),
+        }
+    }
+
+    #[test]
+    fn test_parse_lines_from() {
+        let digits: Vec<Result<(usize, Digit), Error>> = parse_lines_from("1\n\n2x3\n4".as_bytes(), str::parse).collect();
+
+        assert_eq!(digits.len(), 3);
+        assert!(matches!(digits[1], Err(Error::Parse { line: 3, column: 2, .. })));
+        assert_eq!(digits[2].as_ref().unwrap(), &(4, Digit(4)));
+    }
+
+    #[test]
+    fn test_parse_separated() {
+        assert_eq!(parse_separated::<Digit>("1, 23, 4\n", ", ").unwrap(), vec![Digit(1), Digit(23), Digit(4)]);
+
+        match parse_separated::<Digit>("1, 23, 4x", ", ") {
+            Err(e) => assert_eq!(e.in_file("day01.txt").to_string(), "day01.txt:1:9: Invalid digit (in \"1, 23, 4x\")"),
+            x => panic!("Expected Parse, got {:?}", x),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{ocr, Error};
+use crate::files::{self, InputSource};
+use crate::grid::Grid;
+use std::env;
+use std::fmt::{self, Display, Formatter};
+use std::process;
+
+/// A single puzzle answer, typed so that tooling can compare and report on it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// Lit pixels, e.g. a screen spelling out letters.
+    Grid(Grid<bool>),
+    Unsolved,
+}
+
+impl Answer {
+    /// The answer without display framing, for comparing against recorded answers.
+    pub fn to_plain_string(&self) -> String {
+        match self {
+            Answer::Grid(grid) => render(grid),
+            a => a.to_string(),
+        }
+    }
+
+    /// The letters spelled out by a `Grid` answer, if they can be read.
+    pub fn decoded(&self) -> Option<String> {
+        match self {
+            Answer::Grid(grid) => ocr::decode(grid),
+            _ => None,
+        }
+    }
+}
+
+fn render(grid: &Grid<bool>) -> String {
+    grid.map(|&lit| if lit { "# " } else { ". " }).to_string()
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Grid(grid) => write!(f, "\n{}", render(grid)),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Self {
+        Answer::Number(n as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Number(n)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Answer::Number(n as i64)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Number(n as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(o: Option<T>) -> Self {
+        o.map_or(Answer::Unsolved, |x| x.into())
+    }
+}
+
+/// The uniform interface every day implements.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+pub fn input_file_name(day: u8) -> String {
+    format!("day{:02}.txt", day)
+}
+
+/// Solves both parts against `dayNN.txt` in the input directory, or the
+/// `--input <path>` given on the command line, and prints the answers.
+pub fn run<S: Solution>() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let source = InputSource::resolve(&input_file_name(S::DAY), files::input_override(&args));
+
+    let input = match source.parse_with(S::parse) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("day{:02}: {}", S::DAY, e);
+            process::exit(1);
+        }
+    };
+
+    println!("part1: {}", S::part1(&input));
+    println!("part2: {}", S::part2(&input));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(42).to_string(), "42");
+        assert_eq!(Answer::from("abc".to_string()).to_string(), "abc");
+        let grid = Grid::from_rows(vec![vec![true, false], vec![false, true]]).unwrap();
+        assert_eq!(Answer::Grid(grid).to_string(), "\n# . \n. # \n");
+        assert_eq!(Answer::from(None::<u32>), Answer::Unsolved);
+    }
+
+    #[test]
+    fn test_input_file_name() {
+        assert_eq!(input_file_name(4), "day04.txt");
+    }
+}
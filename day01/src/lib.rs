@@ -0,0 +1,440 @@
+use common::Error;
+use common::grid::{Direction, Point};
+use common::parse::{self, LineError};
+use common::solution::{Answer, Solution};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub mod render;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, Error> {
+        parse_instructions(input, ParseMode::Strict)
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Answer {
+        corners(instructions).last()
+            .map(|p| p.manhattan_distance(Point::ORIGIN))
+            .into()
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Answer {
+        first_revisit(instructions)
+            .map(|p| p.manhattan_distance(Point::ORIGIN))
+            .into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TurnDirection {
+    LEFT,
+    RIGHT,
+    /// `U`: turn to face the opposite way.
+    AROUND,
+    /// `F`: keep the current heading.
+    FORWARD,
+    /// `N`, `E`, `S` or `W`: face that way whatever the current heading.
+    FACE(Direction),
+}
+
+impl TurnDirection {
+    pub fn apply(self, heading: Direction) -> Direction {
+        match self {
+            TurnDirection::LEFT => heading.turn_left(),
+            TurnDirection::RIGHT => heading.turn_right(),
+            TurnDirection::AROUND => heading.turn_right().turn_right(),
+            TurnDirection::FORWARD => heading,
+            TurnDirection::FACE(direction) => direction,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    direction: TurnDirection,
+    num_blocks: i64,
+}
+
+impl FromStr for Instruction {
+    type Err = LineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+
+        let direction = match chars.next() {
+            Some('L') => TurnDirection::LEFT,
+            Some('R') => TurnDirection::RIGHT,
+            Some('U') => TurnDirection::AROUND,
+            Some('F') => TurnDirection::FORWARD,
+            Some('N') => TurnDirection::FACE(Direction::North),
+            Some('E') => TurnDirection::FACE(Direction::East),
+            Some('S') => TurnDirection::FACE(Direction::South),
+            Some('W') => TurnDirection::FACE(Direction::West),
+            Some(c) => return Err(LineError::new(1, format!("Invalid turn direction provided: {}", c))),
+            None => return Err(LineError::new(1, "No character found.".to_string())),
+        };
+
+        // Counts are limited to `i32` so that sums of many of them still fit in an `i64`.
+        let num_blocks = chars.as_str().parse::<i32>().ok()
+            .filter(|&n| n >= 0)
+            .map(i64::from)
+            .ok_or_else(|| LineError::new(2, format!("Invalid number of blocks: {}", chars.as_str())))?;
+
+        Ok(Instruction { direction, num_blocks })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParseMode {
+    /// Instructions separated by `", "`; anything unparseable is an error.
+    Strict,
+    /// Instructions separated by any mix of commas and whitespace, in either
+    /// case; anything unparseable is skipped.
+    Lenient,
+}
+
+pub fn parse_instructions(input: &str, mode: ParseMode) -> Result<Vec<Instruction>, Error> {
+    match mode {
+        ParseMode::Strict => parse::parse_separated(input, ", "),
+        ParseMode::Lenient => {
+            Ok(input.split(|c: char| c == ',' || c.is_whitespace())
+                .filter_map(|token| token.to_uppercase().parse().ok())
+                .collect())
+        },
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Metric {
+    Manhattan,
+    Chebyshev,
+    Euclidean,
+}
+
+impl Metric {
+    pub fn distance(self, a: Point, b: Point) -> f64 {
+        let (dx, dy) = ((a.x - b.x).abs() as f64, (a.y - b.y).abs() as f64);
+
+        match self {
+            Metric::Manhattan => dx + dy,
+            Metric::Chebyshev => dx.max(dy),
+            Metric::Euclidean => dx.hypot(dy),
+        }
+    }
+}
+
+/// A point the walk passed through more than once, with the step index
+/// (counting blocks walked, from 1) of every visit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Revisit {
+    pub point: Point,
+    pub visits: usize,
+    pub steps: Vec<usize>,
+}
+
+pub struct State {
+    position: Point,
+    heading: Direction,
+    steps: usize,
+    visits: HashMap<Point, Vec<usize>>,
+    seen_twice_distance: Option<f64>,
+    metric: Metric,
+    reference: Point,
+    farthest_distance: f64,
+}
+
+impl State {
+    pub fn new() -> State {
+        State::with_metric(Metric::Manhattan, Point::ORIGIN)
+    }
+
+    /// A walk from the origin whose distances are measured with `metric` from `reference`.
+    pub fn with_metric(metric: Metric, reference: Point) -> State {
+        State {
+            position: Point::ORIGIN,
+            heading: Direction::North,
+            steps: 0,
+            visits: HashMap::new(),
+            seen_twice_distance: None,
+            metric,
+            reference,
+            farthest_distance: metric.distance(Point::ORIGIN, reference),
+        }
+    }
+
+    pub fn apply_instruction(&mut self, instruction: &Instruction) {
+        self.heading = instruction.direction.apply(self.heading);
+
+        for _ in 0..instruction.num_blocks {
+            self.position = self.position.step(self.heading, 1);
+            self.steps += 1;
+
+            let visits = self.visits.entry(self.position).or_default();
+
+            if !visits.is_empty() && self.seen_twice_distance.is_none() {
+                self.seen_twice_distance = Some(self.metric.distance(self.position, self.reference));
+            }
+            visits.push(self.steps);
+        }
+
+        // Every metric is convex, so no block part-way along a straight walk can
+        // be farther away than both of its ends.
+        self.farthest_distance = self.farthest_distance.max(self.get_distance_from_hq());
+    }
+
+    /// The current distance from the reference point.
+    pub fn get_distance_from_hq(&self) -> f64 {
+        self.metric.distance(self.position, self.reference)
+    }
+
+    /// The greatest distance from the reference point at any block of the walk so far.
+    pub fn farthest_distance(&self) -> f64 {
+        self.farthest_distance
+    }
+
+    pub fn position(&self) -> Point {
+        self.position
+    }
+
+    /// The distance from the reference point to the first block walked onto twice.
+    pub fn seen_twice_distance(&self) -> Option<f64> {
+        self.seen_twice_distance
+    }
+
+    /// Every point visited more than once, ordered by when it was first revisited.
+    /// Standing at the start doesn't count as a visit.
+    pub fn revisits(&self) -> Vec<Revisit> {
+        let mut revisits: Vec<Revisit> = self.visits.iter()
+            .filter(|(_, steps)| steps.len() > 1)
+            .map(|(&point, steps)| Revisit { point, visits: steps.len(), steps: steps.clone() })
+            .collect();
+
+        revisits.sort_by_key(|r| r.steps[1]);
+
+        revisits
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::new()
+    }
+}
+
+/// The blocks walked by one instruction, excluding the one it started on.
+struct Segment {
+    start: Point,
+    heading: Direction,
+    length: i64,
+}
+
+impl Segment {
+    fn end(&self) -> Point {
+        self.start.step(self.heading, self.length)
+    }
+
+    /// The smallest `k` in `1..=length` for which `start.step(heading, k)`
+    /// lies on `other`, if any.
+    fn first_step_on(&self, other: &Segment) -> Option<i64> {
+        let (first, last) = (other.start.step(other.heading, 1), other.end());
+        let offset = self.heading.offset();
+
+        let axes = [
+            (self.start.x, offset.x, first.x.min(last.x), first.x.max(last.x)),
+            (self.start.y, offset.y, first.y.min(last.y), first.y.max(last.y)),
+        ];
+
+        let (mut low, mut high) = (1, self.length);
+
+        for &(from, direction, min, max) in &axes {
+            if direction == 0 {
+                if from < min || from > max {
+                    return None;
+                }
+            } else {
+                let (a, b) = ((min - from) * direction, (max - from) * direction);
+                low = low.max(a.min(b));
+                high = high.min(a.max(b));
+            }
+        }
+
+        if low <= high { Some(low) } else { None }
+    }
+}
+
+/// The first block walked onto twice, found by intersecting each instruction's
+/// segment with the earlier ones rather than visiting every block, so the
+/// cost doesn't depend on how far each instruction walks.
+pub fn first_revisit(instructions: &[Instruction]) -> Option<Point> {
+    let mut walked: Vec<Segment> = Vec::new();
+
+    for segment in segments(instructions) {
+        let first = walked.iter()
+            .filter_map(|other| segment.first_step_on(other))
+            .min();
+
+        if let Some(k) = first {
+            return Some(segment.start.step(segment.heading, k));
+        }
+
+        walked.push(segment);
+    }
+
+    None
+}
+
+/// The start of the walk followed by where each instruction ends.
+pub fn corners(instructions: &[Instruction]) -> Vec<Point> {
+    std::iter::once(Point::ORIGIN)
+        .chain(segments(instructions).map(|s| s.end()))
+        .collect()
+}
+
+/// The non-empty segments walked by `instructions`, in order.
+fn segments(instructions: &[Instruction]) -> impl Iterator<Item = Segment> + '_ {
+    let mut position = Point::ORIGIN;
+    let mut heading = Direction::North;
+
+    instructions.iter().filter_map(move |instruction| {
+        heading = instruction.direction.apply(heading);
+
+        let segment = Segment { start: position, heading, length: instruction.num_blocks };
+        position = segment.end();
+
+        Some(segment).filter(|s| s.length > 0)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day01::part1(&Day01::parse("R2, L3").unwrap()), Answer::Number(5));
+        assert_eq!(Day01::part1(&Day01::parse("R2, R2, R2").unwrap()), Answer::Number(2));
+        assert_eq!(Day01::part1(&Day01::parse("R5, L5, R5, R3").unwrap()), Answer::Number(12));
+        assert_eq!(Day01::part1(&Day01::parse("R1000000, R1000000, L2000000").unwrap()), Answer::Number(4000000));
+        assert_eq!(Day01::part1(&Day01::parse("").unwrap()), Answer::Number(0));
+        assert_eq!(Day01::part1(&Day01::parse("R2147483647, L2147483647").unwrap()), Answer::Number(4294967294));
+        assert_eq!(Day01::part1(&Day01::parse("R2147483647, F2147483647, F2147483647").unwrap()), Answer::Number(6442450941));
+        assert!(Day01::parse("R2147483648").is_err());
+
+        match Day01::parse("R2, L-3") {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (1, 6)),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_metrics() {
+        let mut state = State::new();
+        for instruction in &Day01::parse("R5, L3, L9, L3").unwrap() {
+            state.apply_instruction(instruction);
+        }
+
+        assert_eq!(state.position(), Point::new(-4, 0));
+        assert_eq!(state.get_distance_from_hq(), 4.0);
+        assert_eq!(state.farthest_distance(), 8.0);
+
+        let (a, b) = (Point::new(1, 2), Point::new(4, -2));
+        assert_eq!(Metric::Manhattan.distance(a, b), 7.0);
+        assert_eq!(Metric::Chebyshev.distance(a, b), 4.0);
+        assert_eq!(Metric::Euclidean.distance(a, b), 5.0);
+
+        let mut state = State::with_metric(Metric::Chebyshev, Point::new(-4, 0));
+        assert_eq!(state.farthest_distance(), 4.0);
+        for instruction in &Day01::parse("R5, L3, L9, L3").unwrap() {
+            state.apply_instruction(instruction);
+        }
+
+        assert_eq!(state.get_distance_from_hq(), 0.0);
+        assert_eq!(state.farthest_distance(), 9.0);
+
+        let mut state = State::with_metric(Metric::Euclidean, Point::new(1, 1));
+        for instruction in &Day01::parse("R8, R4, R4, R8").unwrap() {
+            state.apply_instruction(instruction);
+        }
+
+        assert_eq!(state.seen_twice_distance(), Some(Metric::Euclidean.distance(Point::new(4, 0), Point::new(1, 1))));
+    }
+
+    #[test]
+    fn test_extended_instructions() {
+        let instructions = Day01::parse("F3, U1, E2, S4, L1, W2").unwrap();
+        let mut state = State::new();
+        for instruction in &instructions {
+            state.apply_instruction(instruction);
+        }
+
+        assert_eq!(state.position(), Point::new(1, 2));
+        assert_eq!(first_revisit(&instructions), Some(Point::ORIGIN.step(Direction::North, 2)));
+        assert_eq!(corners(&instructions).last(), Some(&Point::new(1, 2)));
+    }
+
+    #[test]
+    fn test_parse_modes() {
+        let input = "r2 ,l3,\n  x9, F1 éé";
+
+        match parse_instructions(input, ParseMode::Strict) {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (1, 1)),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+
+        let instructions = parse_instructions(input, ParseMode::Lenient).unwrap();
+        assert_eq!(Day01::part1(&instructions), Answer::Number(6));
+        assert!("é5".parse::<Instruction>().is_err());
+    }
+
+    #[test]
+    fn test_revisits() {
+        let mut state = State::new();
+        for instruction in &Day01::parse("R8, R4, R4, R8").unwrap() {
+            state.apply_instruction(instruction);
+        }
+
+        assert_eq!(state.revisits(), vec![Revisit { point: Point::new(4, 0), visits: 2, steps: vec![4, 20] }]);
+
+        let mut state = State::new();
+        for instruction in &Day01::parse("R2, R2, R2, R2, R2, R1, R1, R1").unwrap() {
+            state.apply_instruction(instruction);
+        }
+
+        assert_eq!(state.revisits(), vec![
+            Revisit { point: Point::new(1, 0), visits: 3, steps: vec![1, 9, 13] },
+            Revisit { point: Point::new(2, 0), visits: 2, steps: vec![2, 10] },
+            Revisit { point: Point::new(2, 1), visits: 2, steps: vec![3, 11] },
+        ]);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day01::part2(&Day01::parse("R8, R4, R4, R8").unwrap()), Answer::Number(4));
+    }
+
+    #[test]
+    fn test_first_revisit() {
+        for input in &["R8, R4, R4, R8", "R2, R2, R2, R2, R2", "R2, L3, R1", "L5, L2, L2, L5, R9, R9, R1, R1"] {
+            let instructions = Day01::parse(input).unwrap();
+            let mut state = State::new();
+            for instruction in &instructions {
+                state.apply_instruction(instruction);
+            }
+
+            assert_eq!(first_revisit(&instructions), state.revisits().first().map(|r| r.point), "{}", input);
+        }
+
+        let instructions = Day01::parse("R1000000, R1000000, R1000000, R1000000, R2000000").unwrap();
+        assert_eq!(first_revisit(&instructions), Some(Point::new(1, 0)));
+
+        let instructions = Day01::parse("R2147483647, R2, R2, R2147483647").unwrap();
+        assert_eq!(first_revisit(&instructions), Some(Point::new(2147483645, 0)));
+        assert_eq!(Day01::part2(&instructions), Answer::Number(2147483645));
+    }
+}
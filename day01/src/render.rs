@@ -0,0 +1,155 @@
+use crate::{corners, first_revisit, Instruction};
+use common::grid::{Grid, Point};
+
+/// The corners of a walk and the points worth marking on it.
+struct Route {
+    corners: Vec<Point>,
+    hq: Option<Point>,
+    min: Point,
+    max: Point,
+}
+
+impl Route {
+    fn new(instructions: &[Instruction]) -> Self {
+        let corners = corners(instructions);
+        let hq = first_revisit(instructions);
+
+        let min = Point::new(
+            corners.iter().map(|p| p.x).min().unwrap_or(0),
+            corners.iter().map(|p| p.y).min().unwrap_or(0),
+        );
+        let max = Point::new(
+            corners.iter().map(|p| p.x).max().unwrap_or(0),
+            corners.iter().map(|p| p.y).max().unwrap_or(0),
+        );
+
+        Route { corners, hq, min, max }
+    }
+
+    fn start(&self) -> Point {
+        self.corners[0]
+    }
+
+    fn end(&self) -> Point {
+        self.corners[self.corners.len() - 1]
+    }
+
+    fn width(&self) -> i64 {
+        self.max.x - self.min.x
+    }
+
+    fn height(&self) -> i64 {
+        self.max.y - self.min.y
+    }
+}
+
+/// Draws the walk as text no larger than `max_width` by `max_height`, shrinking
+/// it to fit. `S`, `E` and `H` mark the start, the end and HQ (the first block
+/// walked onto twice), with later marks drawn over earlier ones.
+pub fn to_ascii(instructions: &[Instruction], max_width: usize, max_height: usize) -> String {
+    let route = Route::new(instructions);
+
+    let fit = |span: i64, max: usize| {
+        let cells = (max.max(2) - 1) as i64;
+        ((span + cells - 1) / cells).max(1)
+    };
+    let scale = fit(route.width(), max_width).max(fit(route.height(), max_height));
+
+    let cell = |p: Point| Point::new((p.x - route.min.x) / scale, (p.y - route.min.y) / scale);
+
+    let size = cell(route.max);
+    let mut grid = Grid::new(size.x as usize + 1, size.y as usize + 1, ' ');
+
+    for pair in route.corners.windows(2) {
+        let (from, to) = (cell(pair[0]), cell(pair[1]));
+
+        for x in from.x.min(to.x)..=from.x.max(to.x) {
+            for y in from.y.min(to.y)..=from.y.max(to.y) {
+                grid[Point::new(x, y)] = '#';
+            }
+        }
+    }
+
+    grid[cell(route.start())] = 'S';
+    grid[cell(route.end())] = 'E';
+    if let Some(hq) = route.hq {
+        grid[cell(hq)] = 'H';
+    }
+
+    grid.to_string()
+}
+
+/// Draws the walk as an SVG whose view box fits the route with a small margin.
+pub fn to_svg(instructions: &[Instruction]) -> String {
+    let route = Route::new(instructions);
+
+    let span = route.width().max(route.height()).max(1) as f64;
+    let unit = span / 100.0;
+    let margin = unit * 5.0;
+
+    let (width, height) = (route.width() as f64 + 2.0 * margin, route.height() as f64 + 2.0 * margin);
+
+    let points: Vec<String> = route.corners.iter()
+        .map(|p| format!("{},{}", p.x, p.y))
+        .collect();
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\" width=\"600\" height=\"{:.0}\">\n",
+        route.min.x as f64 - margin, route.min.y as f64 - margin, width, height, 600.0 * height / width,
+    );
+    svg += &format!(
+        "  <polyline points=\"{}\" fill=\"none\" stroke=\"black\" stroke-width=\"{}\"/>\n",
+        points.join(" "), unit * 0.5,
+    );
+
+    let mut marks = vec![("start", route.start(), "green"), ("end", route.end(), "blue")];
+    if let Some(hq) = route.hq {
+        marks.push(("hq", hq, "red"));
+    }
+
+    for (name, p, colour) in marks {
+        svg += &format!(
+            "  <circle class=\"{}\" cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{}\"/>\n",
+            name, p.x, p.y, unit * 1.5, colour,
+        );
+    }
+
+    svg += "</svg>\n";
+
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01;
+    use common::solution::Solution;
+
+    #[test]
+    fn test_to_ascii() {
+        let instructions = Day01::parse("R2, R2, R2, R2, R2").unwrap();
+
+        assert_eq!(to_ascii(&instructions, 80, 40), "\
+            SHE\n\
+            # #\n\
+            ###\n");
+
+        let instructions = Day01::parse("R100, R50, L10").unwrap();
+        let rendered = to_ascii(&instructions, 21, 21);
+
+        assert_eq!(rendered.lines().count(), 9);
+        assert!(rendered.lines().all(|l| l.len() == 19));
+        assert!(rendered.starts_with('S'));
+        assert!(!rendered.contains('H'));
+    }
+
+    #[test]
+    fn test_to_svg() {
+        let svg = to_svg(&Day01::parse("R8, R4, R4, R8").unwrap());
+
+        assert!(svg.contains("viewBox=\"-0.4 -4.4 8.8 8.8\""));
+        assert!(svg.contains("points=\"0,0 8,0 8,4 4,4 4,-4\""));
+        assert!(svg.contains("<circle class=\"hq\" cx=\"4\" cy=\"0\""));
+        assert!(svg.contains("<circle class=\"end\" cx=\"4\" cy=\"-4\""));
+    }
+}
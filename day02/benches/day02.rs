@@ -0,0 +1,16 @@
+use common::files;
+use common::solution::{self, Solution};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day02::Day02;
+
+fn bench(c: &mut Criterion) {
+    let raw = files::get_file_as_string(&solution::input_file_name(Day02::DAY));
+    let input = Day02::parse(&raw).unwrap();
+
+    c.bench_function("day02 parse", |b| b.iter(|| Day02::parse(&raw)));
+    c.bench_function("day02 part1", |b| b.iter(|| Day02::part1(&input)));
+    c.bench_function("day02 part2", |b| b.iter(|| Day02::part2(&input)));
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,564 @@
+use common::Error;
+use common::grid::{Direction, Grid, Point};
+use common::parse::LineError;
+use common::solution::{Answer, Solution};
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+pub mod render;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Moves>;
+
+    fn parse(input: &str) -> Result<Vec<Moves>, Error> {
+        parse_moves(input, ParseMode::Strict, &Move::STRAIGHT)
+    }
+
+    fn part1(lines: &Vec<Moves>) -> Answer {
+        solve(&mut Keypad::new_part1(), lines).into()
+    }
+
+    fn part2(lines: &Vec<Moves>) -> Answer {
+        solve(&mut Keypad::new_part2(), lines).into()
+    }
+}
+
+pub fn solve(keypad: &mut Keypad, lines: &[Moves]) -> String {
+    let mut code = String::new();
+
+    for line in lines {
+        for d in &line.0 {
+            keypad.do_move(d);
+        }
+
+        code.push(keypad.current_char());
+    }
+
+    code
+}
+
+/// The shortest instructions that type `code` on `keypad` from its current
+/// button, or `None` if some button in the code can't be reached.
+pub fn moves_for(keypad: &Keypad, code: &str) -> Option<Vec<Moves>> {
+    // The cheapest instructions found so far that end on each candidate button.
+    let mut best: BTreeMap<Point, Vec<Moves>> = BTreeMap::new();
+    best.insert(keypad.position, Vec::new());
+
+    for c in code.chars() {
+        let mut next: BTreeMap<Point, Vec<Moves>> = BTreeMap::new();
+
+        for (&from, lines) in &best {
+            for (to, path) in keypad.paths_from(from) {
+                if keypad.button_at(to) != Some(c) {
+                    continue;
+                }
+
+                let cost = total_moves(lines) + path.len();
+
+                if next.get(&to).is_none_or(|existing| cost < total_moves(existing)) {
+                    let mut lines = lines.clone();
+                    lines.push(Moves(path));
+                    next.insert(to, lines);
+                }
+            }
+        }
+
+        if next.is_empty() {
+            return None;
+        }
+
+        best = next;
+    }
+
+    best.into_values()
+        .min_by_key(|lines| total_moves(lines))
+}
+
+fn total_moves(lines: &[Moves]) -> usize {
+    lines.iter().map(|m| m.0.len()).sum()
+}
+
+/// One line of the instructions: the moves made before pressing a button.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Moves(pub Vec<Move>);
+
+impl Display for Moves {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for &m in &self.0 {
+            write!(f, "{}", m.to_char())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Moves {
+    type Err = LineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Moves::parse(s, ParseMode::Strict, &Move::STRAIGHT)
+    }
+}
+
+impl Moves {
+    /// Reads a line in which every move must be one of `allowed`, such as
+    /// `Move::STRAIGHT` or, for keypads with diagonals, `Move::ALL`.
+    pub fn parse(s: &str, mode: ParseMode, allowed: &[Move]) -> Result<Moves, LineError> {
+        s.chars()
+            .enumerate()
+            .filter(|(_, c)| mode == ParseMode::Strict || !c.is_whitespace())
+            .map(|(i, c)| {
+                Move::from_char(c)
+                    .and_then(|m| if allowed.contains(&m) { Ok(m) } else { Err(format!("Move not allowed: {:?}", c)) })
+                    .map_err(|e| LineError::new(i + 1, e))
+            })
+            .collect::<Result<Vec<Move>, LineError>>()
+            .map(Moves)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParseMode {
+    /// Every character of a line must be a move.
+    Strict,
+    /// Whitespace within a line, such as a stray `\r`, is skipped.
+    Lenient,
+}
+
+/// Parses one line of moves per line of `input`. A blank line has no moves, so
+/// presses the same button again. Errors give the line and column of the
+/// first character that isn't one of the `allowed` moves.
+pub fn parse_moves(input: &str, mode: ParseMode, allowed: &[Move]) -> Result<Vec<Moves>, Error> {
+    input.lines()
+        .enumerate()
+        .map(|(i, line)| {
+            Moves::parse(line, mode, allowed).map_err(|e| Error::Parse {
+                file: None,
+                line: i + 1,
+                column: e.column,
+                text: line.to_string(),
+                message: e.message,
+            })
+        })
+        .collect()
+}
+
+/// A single move on a keypad. The diagonals are written with the keys around
+/// `S` on a keyboard: `Q` up-left, `E` up-right, `Z` down-left, `C` down-right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    Straight(Direction),
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Move {
+    pub const STRAIGHT: [Move; 4] = [
+        Move::Straight(Direction::North),
+        Move::Straight(Direction::East),
+        Move::Straight(Direction::South),
+        Move::Straight(Direction::West),
+    ];
+
+    pub const ALL: [Move; 8] = [
+        Move::Straight(Direction::North),
+        Move::Straight(Direction::East),
+        Move::Straight(Direction::South),
+        Move::Straight(Direction::West),
+        Move::UpLeft,
+        Move::UpRight,
+        Move::DownLeft,
+        Move::DownRight,
+    ];
+
+    pub fn from_char(c: char) -> Result<Move, String> {
+        match c {
+            'U' => Ok(Move::Straight(Direction::North)),
+            'R' => Ok(Move::Straight(Direction::East)),
+            'D' => Ok(Move::Straight(Direction::South)),
+            'L' => Ok(Move::Straight(Direction::West)),
+            'Q' => Ok(Move::UpLeft),
+            'E' => Ok(Move::UpRight),
+            'Z' => Ok(Move::DownLeft),
+            'C' => Ok(Move::DownRight),
+            x => Err(format!("Invalid direction provided: {:?}", x)),
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Move::Straight(Direction::North) => 'U',
+            Move::Straight(Direction::East) => 'R',
+            Move::Straight(Direction::South) => 'D',
+            Move::Straight(Direction::West) => 'L',
+            Move::UpLeft => 'Q',
+            Move::UpRight => 'E',
+            Move::DownLeft => 'Z',
+            Move::DownRight => 'C',
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        !matches!(self, Move::Straight(_))
+    }
+
+    pub fn offset(self) -> Point {
+        match self {
+            Move::Straight(d) => d.offset(),
+            Move::UpLeft => Point::new(-1, -1),
+            Move::UpRight => Point::new(1, -1),
+            Move::DownLeft => Point::new(-1, 1),
+            Move::DownRight => Point::new(1, 1),
+        }
+    }
+}
+
+impl From<Direction> for Move {
+    fn from(d: Direction) -> Self {
+        Move::Straight(d)
+    }
+}
+
+/// One move attempted on a keypad. A blocked move leaves `from` and `to` equal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    pub direction: Move,
+    pub from: Point,
+    pub to: Point,
+}
+
+impl Step {
+    pub fn blocked(&self) -> bool {
+        self.from == self.to
+    }
+}
+
+#[derive(Debug)]
+pub struct Keypad {
+    position: Point,
+    buttons: Grid<Option<char>>,
+    trace: Option<Vec<Step>>,
+    diagonal: bool,
+    wrap: bool,
+}
+
+const PART1_LAYOUT: &str = concat!(
+    "123\n",
+    "45*6\n",
+    "789\n",
+);
+
+const PART2_LAYOUT: &str = concat!(
+    "  1\n",
+    " 234\n",
+    "5*6789\n",
+    " ABC\n",
+    "  D\n",
+);
+
+impl Keypad {
+    pub fn new_part1() -> Keypad {
+        PART1_LAYOUT.parse().unwrap()
+    }
+
+    pub fn new_part2() -> Keypad {
+        PART2_LAYOUT.parse().unwrap()
+    }
+
+    /// Allows the diagonal moves, which are otherwise always blocked.
+    pub fn with_diagonals(mut self) -> Keypad {
+        self.diagonal = true;
+        self
+    }
+
+    /// Makes moves off an edge or onto a hole carry on in the same direction,
+    /// wrapping around the edges, until they reach a button.
+    pub fn with_wrap(mut self) -> Keypad {
+        self.wrap = true;
+        self
+    }
+
+    pub fn do_move(&mut self, m: &Move) {
+        let from = self.position;
+        self.position = self.destination(from, *m);
+
+        if let Some(trace) = &mut self.trace {
+            trace.push(Step { direction: *m, from, to: self.position });
+        }
+    }
+
+    /// Where `m` leads from `from`; `from` itself if the move is blocked.
+    fn destination(&self, from: Point, m: Move) -> Point {
+        if m.is_diagonal() && !self.diagonal {
+            return from;
+        }
+
+        let (width, height) = (self.buttons.width() as i64, self.buttons.height() as i64);
+        let mut next = from + m.offset();
+
+        if !self.wrap {
+            return if self.button_at(next).is_some() { next } else { from };
+        }
+
+        while next != from {
+            next = Point::new(next.x.rem_euclid(width), next.y.rem_euclid(height));
+
+            if self.button_at(next).is_some() {
+                return next;
+            }
+
+            next = next + m.offset();
+        }
+
+        from
+    }
+
+    fn moves(&self) -> &'static [Move] {
+        if self.diagonal { &Move::ALL } else { &Move::STRAIGHT }
+    }
+
+    /// Starts recording every move made, including blocked ones.
+    pub fn start_trace(&mut self) {
+        self.trace.get_or_insert_with(Vec::new);
+    }
+
+    /// The moves recorded since tracing started or the trace was last taken.
+    pub fn take_trace(&mut self) -> Vec<Step> {
+        self.trace.as_mut().map(std::mem::take).unwrap_or_default()
+    }
+
+    pub fn position(&self) -> Point {
+        self.position
+    }
+
+    pub fn current_char(&self) -> char {
+        self.button_at(self.position).unwrap()
+    }
+
+    pub fn button_at(&self, p: Point) -> Option<char> {
+        self.buttons.get(p).cloned().flatten()
+    }
+
+    /// The shortest moves from `from` to every button reachable from it.
+    fn paths_from(&self, from: Point) -> BTreeMap<Point, Vec<Move>> {
+        let mut paths = BTreeMap::new();
+        paths.insert(from, Vec::new());
+
+        let mut queue = VecDeque::new();
+        queue.push_back(from);
+
+        while let Some(p) = queue.pop_front() {
+            for &m in self.moves() {
+                let next = self.destination(p, m);
+
+                if !paths.contains_key(&next) {
+                    let mut path = paths[&p].clone();
+                    path.push(m);
+                    paths.insert(next, path);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        paths
+    }
+}
+
+/// Reads a layout with one character per button, spaces for holes and a `*`
+/// straight after the starting button. The `*` doesn't take up a column, and
+/// short lines are padded with holes.
+impl FromStr for Keypad {
+    type Err = Error;
+
+    fn from_str(layout: &str) -> Result<Self, Self::Err> {
+        let error = |line: usize, column: usize, message: &str| Error::Parse {
+            file: None,
+            line,
+            column,
+            text: layout.lines().nth(line - 1).unwrap_or("").to_string(),
+            message: message.to_string(),
+        };
+
+        let mut rows: Vec<Vec<Option<char>>> = Vec::new();
+        let mut start = None;
+
+        for (y, line) in layout.lines().enumerate() {
+            let mut row = Vec::new();
+
+            for (i, c) in line.chars().enumerate() {
+                match c {
+                    '*' => {
+                        if start.is_some() {
+                            return Err(error(y + 1, i + 1, "More than one start marker."));
+                        }
+                        if row.last().cloned().flatten().is_none() {
+                            return Err(error(y + 1, i + 1, "Start marker doesn't follow a button."));
+                        }
+
+                        start = Some(Point::new(row.len() as i64 - 1, y as i64));
+                    },
+                    ' ' => row.push(None),
+                    c => row.push(Some(c)),
+                }
+            }
+
+            rows.push(row);
+        }
+
+        let start = start.ok_or_else(|| error(1, 1, "No start marker."))?;
+
+        let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+        for row in &mut rows {
+            row.resize(width, None);
+        }
+
+        Ok(Keypad {
+            position: start,
+            buttons: Grid::from_rows(rows).unwrap(),
+            trace: None,
+            diagonal: false,
+            wrap: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::parse;
+    use std::borrow::{Borrow, BorrowMut};
+
+    #[test]
+    fn test_solve() {
+        let input: Vec<Moves> = parse::parse_lines("ULL\nRRDDD\nLURDL\nUUUUD").unwrap();
+
+        assert_eq!(
+            solve(Keypad::new_part1().borrow_mut(), input.borrow()),
+            "1985".to_string()
+        );
+        assert_eq!(
+            solve(Keypad::new_part2().borrow_mut(), input.borrow()),
+            "5DB3".to_string()
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut keypad = Keypad::new_part1();
+        keypad.do_move(&Direction::North.into());
+        assert!(keypad.take_trace().is_empty());
+
+        keypad.start_trace();
+        for d in &"ULD".parse::<Moves>().unwrap().0 {
+            keypad.do_move(d);
+        }
+
+        let trace = keypad.take_trace();
+        assert_eq!(trace.iter().map(|s| s.to).collect::<Vec<_>>(), vec![Point::new(1, 0), Point::new(0, 0), Point::new(0, 1)]);
+        assert!(trace[0].blocked());
+        assert!(!trace[2].blocked());
+        assert!(keypad.take_trace().is_empty());
+    }
+
+    #[test]
+    fn test_diagonal_and_wrap() {
+        assert!(Day02::parse("QQ\nCZ\nEE").is_err());
+        let input = parse_moves("QQ\nCZ\nEE", ParseMode::Strict, &Move::ALL).unwrap();
+
+        assert_eq!(solve(&mut Keypad::new_part1(), &input), "555");
+        assert_eq!(solve(&mut Keypad::new_part1().with_diagonals(), &input), "173");
+        assert_eq!(solve(&mut Keypad::new_part2().with_diagonals(), &input), "5A4");
+
+        let input: Vec<Moves> = parse::parse_lines("L\nUU\nRRRR\nDDD").unwrap();
+
+        assert_eq!(solve(&mut Keypad::new_part1().with_wrap(), &input), "4788");
+        assert_eq!(solve(&mut Keypad::new_part2().with_wrap(), &input), "9988");
+        assert_eq!(solve(&mut "1*".parse::<Keypad>().unwrap().with_wrap().with_diagonals(), &input), "1111");
+
+        let keypad = Keypad::new_part2().with_diagonals().with_wrap();
+        let moves = moves_for(&keypad, "1D9").unwrap();
+        assert_eq!(total_moves(&moves), 3);
+        assert_eq!(solve(&mut Keypad::new_part2().with_diagonals().with_wrap(), &moves), "1D9");
+    }
+
+    #[test]
+    fn test_moves_for() {
+        let moves = moves_for(&Keypad::new_part1(), "1985").unwrap();
+
+        assert_eq!(moves.iter().map(|m| m.to_string()).collect::<Vec<_>>(), vec!["UL", "RRDD", "L", "U"]);
+
+        for (keypad, code) in &[(Keypad::new_part1 as fn() -> Keypad, "1985"), (Keypad::new_part2, "5DB3"), (Keypad::new_part2, "D1D95")] {
+            let moves = moves_for(&keypad(), code).unwrap();
+            assert_eq!(&solve(&mut keypad(), &moves), code);
+        }
+
+        assert_eq!(moves_for(&Keypad::new_part1(), "5"), Some(vec![Moves(vec![])]));
+
+        for code in &["155", "5", "9115"] {
+            let text: String = moves_for(&Keypad::new_part1(), code).unwrap().iter()
+                .map(|m| format!("{}\n", m))
+                .collect();
+
+            assert_eq!(Day02::part1(&Day02::parse(&text).unwrap()), Answer::Text(code.to_string()));
+        }
+
+        assert_eq!(Day02::part1(&Day02::parse("UL\n\nR\n").unwrap()), Answer::Text("112".to_string()));
+        assert_eq!(moves_for(&Keypad::new_part1(), "1A"), None);
+        assert_eq!(total_moves(&moves_for(&"1*234\nX  5".parse().unwrap(), "X5").unwrap()), 6);
+    }
+
+    #[test]
+    fn test_keypad_from_layout() {
+        let mut keypad: Keypad = "AB\n C*\nD".parse().unwrap();
+
+        assert_eq!(keypad.position(), Point::new(1, 1));
+        assert_eq!(keypad.button_at(Point::new(1, 2)), None);
+        assert_eq!(solve(&mut keypad, &parse::parse_lines("UL\nDLL\nRD").unwrap()), "AAC");
+
+        for (layout, expected) in &[("12\n34", (1, 1)), ("1*2*", (1, 4)), ("1\n *2", (2, 2))] {
+            match layout.parse::<Keypad>() {
+                Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), *expected, "{:?}", layout),
+                x => panic!("Expected Parse, got {:?}", x),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_modes() {
+        let input = "UL\r\nR D \r\n\nLX\n";
+
+        match parse_moves(input, ParseMode::Strict, &Move::STRAIGHT) {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (2, 2)),
+            x => panic!("Expected Parse, got {:?}", x),
+        }
+
+        match parse_moves(input, ParseMode::Lenient, &Move::STRAIGHT) {
+            Err(e) => assert_eq!(e.to_string(), "4:2: Invalid direction provided: 'X' (in \"LX\")"),
+            x => panic!("Expected Parse, got {:?}", x),
+        }
+
+        let moves = parse_moves(&input.replace('X', ""), ParseMode::Lenient, &Move::STRAIGHT).unwrap();
+        assert_eq!(moves.iter().map(|m| m.to_string()).collect::<Vec<_>>(), vec!["UL", "RD", "", "L"]);
+        assert!(Day02::parse("U\rL").is_err());
+
+        match Day02::parse("UEC") {
+            Err(e) => assert_eq!(e.to_string(), "1:2: Move not allowed: 'E' (in \"UEC\")"),
+            x => panic!("Expected Parse, got {:?}", x),
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid_direction() {
+        match Day02::parse("ULL\nRRXDD") {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (2, 3)),
+            x => panic!("Expected Parse, got {:?}", x),
+        }
+    }
+}
\ No newline at end of file
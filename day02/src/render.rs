@@ -0,0 +1,93 @@
+use crate::{Keypad, Moves};
+use common::grid::Point;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+/// Draws the keypad with the button at `current` in brackets.
+pub fn to_text(keypad: &Keypad, current: Point) -> String {
+    let mut text = String::new();
+
+    for y in 0..keypad.buttons.height() as i64 {
+        let row: String = (0..keypad.buttons.width() as i64)
+            .map(|x| {
+                let p = Point::new(x, y);
+
+                match keypad.button_at(p) {
+                    Some(c) if p == current => format!("[{}]", c),
+                    Some(c) => format!(" {} ", c),
+                    None => "   ".to_string(),
+                }
+            })
+            .collect();
+
+        text += row.trim_end();
+        text.push('\n');
+    }
+
+    text
+}
+
+/// Runs `lines` on `keypad`, drawing it after every move and every button press.
+pub fn frames(keypad: &mut Keypad, lines: &[Moves]) -> Vec<String> {
+    let mut frames = Vec::new();
+    keypad.start_trace();
+
+    for (i, line) in lines.iter().enumerate() {
+        for d in &line.0 {
+            keypad.do_move(d);
+        }
+
+        for (j, step) in keypad.take_trace().iter().enumerate() {
+            let blocked = if step.blocked() { " (blocked)" } else { "" };
+
+            frames.push(format!(
+                "line {} move {}: {}{}\n{}",
+                i + 1, j + 1, step.direction.to_char(), blocked, to_text(keypad, step.to),
+            ));
+        }
+
+        frames.push(format!("line {}: press {}\n{}", i + 1, keypad.current_char(), to_text(keypad, keypad.position())));
+    }
+
+    frames
+}
+
+/// Plays `frames` on a terminal, clearing the screen before each one.
+pub fn animate<W: Write>(out: &mut W, frames: &[String], delay: Duration) -> io::Result<()> {
+    for frame in frames {
+        write!(out, "\x1b[2J\x1b[H{}", frame)?;
+        out.flush()?;
+        thread::sleep(delay);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::parse;
+
+    #[test]
+    fn test_frames() {
+        let lines: Vec<Moves> = parse::parse_lines("UL\nR").unwrap();
+        let frames = frames(&mut Keypad::new_part2(), &lines);
+
+        assert_eq!(frames.len(), 5);
+        assert_eq!(frames[1], "\
+line 1 move 2: L (blocked)
+       1
+    2  3  4
+[5] 6  7  8  9
+    A  B  C
+       D
+");
+        assert_eq!(frames[4].lines().next(), Some("line 2: press 6"));
+        assert!(frames[4].contains("[6]"));
+
+        let mut out = Vec::new();
+        animate(&mut out, &frames[..2], Duration::from_millis(0)).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap().matches("\x1b[2J").count(), 2);
+    }
+}
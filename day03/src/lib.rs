@@ -0,0 +1,481 @@
+use common::Error;
+use common::parse::{self, LineError};
+use common::solution::{Answer, Solution};
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::fmt::{self, Display, Formatter};
+use std::io::BufRead;
+use std::ops::Sub;
+use std::str::FromStr;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Row>;
+
+    fn parse(input: &str) -> Result<Vec<Row>, Error> {
+        parse::parse_lines(input)
+    }
+
+    fn part1(rows: &Vec<Row>) -> Answer {
+        solve_part1(rows).into()
+    }
+
+    /// Unsolved if the rows don't divide into groups of three; `count_part2`
+    /// reports the line those leftover rows start on.
+    fn part2(rows: &Vec<Row>) -> Answer {
+        solve_part2(rows).ok().into()
+    }
+}
+
+/// The three side lengths on one line of the input.
+#[derive(Debug, PartialEq)]
+pub struct Row(pub [u32; 3]);
+
+impl FromStr for Row {
+    type Err = LineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse_sides(s)?[..] {
+            [a, b, c] => Ok(Row([a, b, c])),
+            ref sides => Err(LineError::new(1, format!("Expected 3 sides, found {}", sides.len()))),
+        }
+    }
+}
+
+/// The side lengths of a shape with any number of sides.
+#[derive(Debug, PartialEq)]
+pub struct Polygon(pub Vec<u32>);
+
+/// Reads a polygon of any width.
+impl FromStr for Polygon {
+    type Err = LineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_sides(s).map(Polygon)
+    }
+}
+
+impl Polygon {
+    /// Reads a polygon that must have exactly `count` sides.
+    pub fn parse_sides(s: &str, count: usize) -> Result<Polygon, LineError> {
+        let polygon: Polygon = s.parse()?;
+
+        if polygon.0.len() != count {
+            return Err(LineError::new(1, format!("Expected {} sides, found {}", count, polygon.0.len())));
+        }
+
+        Ok(polygon)
+    }
+}
+
+fn parse_sides(s: &str) -> Result<Vec<u32>, LineError> {
+    let mut sides = Vec::new();
+    let mut offset = 0;
+
+    for side in s.split_whitespace() {
+        let start = offset + s[offset..].find(side).unwrap();
+        let column = start + 1;
+        offset = start + side.len();
+
+        sides.push(side.parse::<u32>()
+            .map_err(|_| LineError::new(column, format!("Invalid side length: {}", side)))?);
+    }
+
+    Ok(sides)
+}
+
+/// How rows of numbers are read as triangles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Grouping {
+    /// Each row is a triangle.
+    Rows,
+    /// Each column of each group of three rows is a triangle.
+    Columns,
+    /// Each wrapping diagonal of each group of three rows is a triangle,
+    /// starting from each number in the group's first row.
+    Diagonals,
+    /// Each column of every three consecutive rows is a triangle, so every
+    /// row but the first two and last two is read three times.
+    Window,
+}
+
+impl FromStr for Grouping {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rows" => Ok(Grouping::Rows),
+            "columns" => Ok(Grouping::Columns),
+            "diagonals" => Ok(Grouping::Diagonals),
+            "window" => Ok(Grouping::Window),
+            x => Err(format!("Invalid grouping \"{}\" specified.", x)),
+        }
+    }
+}
+
+/// Rows left over at the end that don't make up a whole group.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncompleteGroup {
+    /// The 1-based number of the first leftover row.
+    pub row: usize,
+    pub rows: usize,
+}
+
+impl IncompleteGroup {
+    /// A parse error pointing at the first leftover row, found at `line` with `text`.
+    pub fn at_line(&self, line: usize, text: &str) -> Error {
+        Error::Parse { file: None, line, column: 1, text: text.to_string(), message: self.to_string() }
+    }
+}
+
+impl Display for IncompleteGroup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} row(s) from row {} don't make a whole group of 3", self.rows, self.row)
+    }
+}
+
+/// Reads triangles from rows fed in one at a time, holding at most three rows.
+pub struct Grouper {
+    grouping: Grouping,
+    rows: usize,
+    group: Vec<[u32; 3]>,
+}
+
+impl Grouper {
+    pub fn new(grouping: Grouping) -> Self {
+        Grouper { grouping, rows: 0, group: Vec::with_capacity(3) }
+    }
+
+    /// Adds the next row and returns the triangles it completes.
+    pub fn push(&mut self, row: [u32; 3]) -> Vec<[u32; 3]> {
+        self.rows += 1;
+
+        if self.grouping == Grouping::Rows {
+            return vec![row];
+        }
+
+        self.group.push(row);
+
+        if self.group.len() < 3 {
+            return Vec::new();
+        }
+
+        let g = &self.group;
+        let triangles = match self.grouping {
+            Grouping::Diagonals => (0..3).map(|x| [g[0][x], g[1][(x + 1) % 3], g[2][(x + 2) % 3]]).collect(),
+            _ => (0..3).map(|x| [g[0][x], g[1][x], g[2][x]]).collect(),
+        };
+
+        if self.grouping == Grouping::Window {
+            self.group.remove(0);
+        } else {
+            self.group.clear();
+        }
+
+        triangles
+    }
+
+    /// Checks nothing is left over once every row has been pushed.
+    pub fn finish(self) -> Result<(), IncompleteGroup> {
+        match self.grouping {
+            Grouping::Columns | Grouping::Diagonals if !self.group.is_empty() => {
+                Err(IncompleteGroup { row: self.rows - self.group.len() + 1, rows: self.group.len() })
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
+pub fn count_triangles(rows: &[Row], grouping: Grouping) -> Result<u32, IncompleteGroup> {
+    let mut grouper = Grouper::new(grouping);
+    let mut count = 0;
+
+    for row in rows {
+        count += count_valid(grouper.push(row.0));
+    }
+
+    grouper.finish().map(|_| count)
+}
+
+/// `count_triangles` over rows read one at a time from `reader`. Leftover rows
+/// are reported as a parse error on the first of them.
+pub fn count_from<R: BufRead>(reader: R, grouping: Grouping) -> Result<u32, Error> {
+    let mut grouper = Grouper::new(grouping);
+    let mut count = 0;
+    // The line number and text of the last few rows, to point at leftovers.
+    let mut recent: VecDeque<(usize, String)> = VecDeque::with_capacity(3);
+
+    let rows = parse::parse_lines_from(reader, |line| {
+        line.parse::<Row>().map(|row| (row, line.to_string()))
+    });
+
+    for row in rows {
+        let (line, (row, text)) = row?;
+        count += count_valid(grouper.push(row.0));
+
+        if recent.len() == 3 {
+            recent.pop_front();
+        }
+        recent.push_back((line, text));
+    }
+
+    grouper.finish()
+        .map(|_| count)
+        .map_err(|e| {
+            let (line, text) = &recent[recent.len() - e.rows];
+            e.at_line(*line, text)
+        })
+}
+
+fn count_valid(triangles: Vec<[u32; 3]>) -> u32 {
+    triangles.into_iter().filter(|&t| is_valid_triangle(t)).count() as u32
+}
+
+pub fn solve_part1(rows: &[Row]) -> u32 {
+    count_triangles(rows, Grouping::Rows).unwrap()
+}
+
+pub fn solve_part2(rows: &[Row]) -> Result<u32, IncompleteGroup> {
+    count_triangles(rows, Grouping::Columns)
+}
+
+pub fn count_part1<R: BufRead>(reader: R) -> Result<u32, Error> {
+    count_from(reader, Grouping::Rows)
+}
+
+pub fn count_part2<R: BufRead>(reader: R) -> Result<u32, Error> {
+    count_from(reader, Grouping::Columns)
+}
+
+pub fn count_polygons(polygons: &[Polygon]) -> u32 {
+    polygons.iter().filter(|p| is_valid_polygon(&p.0)).count() as u32
+}
+
+/// An integer type that can be a side length.
+pub trait Side: Copy + Ord + Sub<Output = Self> {
+    const ZERO: Self;
+
+    /// Widens a non-negative value, so squares of any side fit.
+    fn widen(self) -> u128;
+}
+
+macro_rules! impl_side {
+    ($($t:ty),*) => {
+        $(
+            impl Side for $t {
+                const ZERO: Self = 0;
+
+                fn widen(self) -> u128 {
+                    self as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_side!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriangleKind {
+    /// A negative side, or one side longer than the other two put together.
+    Invalid,
+    /// One side exactly as long as the other two, so the triangle is flat.
+    Degenerate,
+    Equilateral,
+    Isosceles,
+    /// Sides that are all different and satisfy Pythagoras. Integer right
+    /// triangles are never isosceles, so this never hides one.
+    RightAngled,
+    Scalene,
+}
+
+pub fn is_valid_triangle<T: Side>(sides: [T; 3]) -> bool {
+    !matches!(classify(sides), TriangleKind::Invalid | TriangleKind::Degenerate)
+}
+
+/// Whether `sides` can close into a polygon: at least three of them, none
+/// negative, with the longest shorter than the rest put together.
+pub fn is_valid_polygon<T: Side>(sides: &[T]) -> bool {
+    let longest = match sides.iter().max() {
+        Some(&longest) if sides.len() >= 3 => longest,
+        _ => return false,
+    };
+
+    if sides.iter().any(|&s| s < T::ZERO) {
+        return false;
+    }
+
+    // Takes each other side off what's left of the longest rather than adding
+    // them up, so nothing can overflow.
+    let mut remaining = longest;
+    let others = sides.iter().position(|&s| s == longest)
+        .map(|i| sides[..i].iter().chain(&sides[i + 1..]));
+
+    for &side in others.into_iter().flatten() {
+        if side > remaining {
+            return true;
+        }
+
+        remaining = remaining - side;
+    }
+
+    false
+}
+
+/// Classifies the triangle with the given sides without risk of overflow.
+pub fn classify<T: Side>(mut sides: [T; 3]) -> TriangleKind {
+    sides.sort();
+    let [a, b, c] = sides;
+
+    if a < T::ZERO {
+        return TriangleKind::Invalid;
+    }
+
+    // `a + b > c` rearranged so nothing can overflow: `c - b` is never negative.
+    match a.cmp(&(c - b)) {
+        Ordering::Less => TriangleKind::Invalid,
+        Ordering::Equal => TriangleKind::Degenerate,
+        Ordering::Greater if a == c => TriangleKind::Equilateral,
+        Ordering::Greater if a == b || b == c => TriangleKind::Isosceles,
+        Ordering::Greater => {
+            let (a, b, c) = (a.widen(), b.widen(), c.widen());
+
+            if (a * a).checked_add(b * b) == Some(c * c) {
+                TriangleKind::RightAngled
+            } else {
+                TriangleKind::Scalene
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_triangle() {
+        assert!(!is_valid_triangle([5, 10, 25]));
+        assert!(!is_valid_triangle([5, 10, 5]));
+        assert!(is_valid_triangle([5, 9, 5]));
+        assert!(is_valid_triangle([u32::MAX, u32::MAX, 1]));
+        assert!(!is_valid_triangle([u64::MAX, 1, u64::MAX - 1]));
+        assert!(!is_valid_triangle([-3i32, 4, 5]));
+        assert!(!is_valid_triangle([i8::MIN, i8::MAX, i8::MAX]));
+    }
+
+    #[test]
+    fn test_is_valid_polygon() {
+        assert!(is_valid_polygon(&[1, 1, 1, 2]));
+        assert!(!is_valid_polygon(&[1, 1, 1, 3]));
+        assert!(!is_valid_polygon(&[1, 1, 1, 4]));
+        assert!(is_valid_polygon(&[2, 3, 4, 5, 13]));
+        assert!(!is_valid_polygon(&[2, 3, 4, 5, 14]));
+        assert!(is_valid_polygon(&[u32::MAX, u32::MAX, u32::MAX, u32::MAX]));
+        assert!(!is_valid_polygon(&[-1i32, 5, 5, 5]));
+        assert!(!is_valid_polygon::<u32>(&[5, 5]));
+        assert!(!is_valid_polygon::<u32>(&[]));
+
+        for sides in &[[5, 10, 25], [5, 10, 5], [5, 9, 5], [3, 4, 5]] {
+            assert_eq!(is_valid_polygon(sides), is_valid_triangle(*sides));
+        }
+
+        let polygons: Vec<Polygon> = parse::parse_lines("3 4 5\n1 1 1 1\n1 1 1 3\n1 2").unwrap();
+        assert_eq!(count_polygons(&polygons), 2);
+        assert_eq!(Polygon::parse_sides("1 2 3 4", 4), Ok(Polygon(vec![1, 2, 3, 4])));
+        assert!(Polygon::parse_sides("1 2 3", 4).is_err());
+    }
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(classify([5, 10, 25]), TriangleKind::Invalid);
+        assert_eq!(classify([0u8, 0, 0]), TriangleKind::Degenerate);
+        assert_eq!(classify([10, 5, 5]), TriangleKind::Degenerate);
+        assert_eq!(classify([7i16, 7, 7]), TriangleKind::Equilateral);
+        assert_eq!(classify([7usize, 3, 7]), TriangleKind::Isosceles);
+        assert_eq!(classify([13i64, 5, 12]), TriangleKind::RightAngled);
+        assert_eq!(classify([u64::MAX - 1, u64::MAX, 2]), TriangleKind::Scalene);
+        assert_eq!(classify([4, 5, 6]), TriangleKind::Scalene);
+    }
+
+    #[test]
+    fn test_row_from_str() {
+        assert_eq!("  5  10   25".parse::<Row>(), Ok(Row([5, 10, 25])));
+        assert_eq!("5 1x 25".parse::<Row>().unwrap_err().column, 3);
+        assert!("5 10".parse::<Row>().is_err());
+        assert!("5 10 25 1".parse::<Row>().is_err());
+        assert!(Day03::parse("5 10 25\n1 2 3 4").is_err());
+    }
+
+    #[test]
+    fn test_solve_part1() {
+        let rows = Day03::parse("\
+            101 301 501\n\
+            102 302 502\n\
+            103 303 503\n\
+            201 401 601\n\
+            202 402 602\n\
+            203 403 603").unwrap();
+
+        assert_eq!(solve_part1(&rows), 3);
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let rows = Day03::parse("\
+            101 301 501\n\
+            102 302 502\n\
+            103 303 503\n\
+            201 401 601\n\
+            202 402 602\n\
+            203 403 603").unwrap();
+
+        assert_eq!(solve_part2(&rows), Ok(6));
+        assert_eq!(solve_part2(&rows[..5]), Err(IncompleteGroup { row: 4, rows: 2 }));
+    }
+
+    #[test]
+    fn test_groupings() {
+        let rows: Vec<Row> = parse::parse_lines("3 1 9\n9 4 1\n1 9 5\n8 6 4").unwrap();
+
+        assert_eq!(count_triangles(&rows, "rows".parse().unwrap()), Ok(1));
+        assert_eq!(count_triangles(&rows[..3], "columns".parse().unwrap()), Ok(0));
+        assert_eq!(count_triangles(&rows[..3], "diagonals".parse().unwrap()), Ok(3));
+        assert_eq!(count_triangles(&rows, "diagonals".parse().unwrap()), Err(IncompleteGroup { row: 4, rows: 1 }));
+        assert_eq!(count_triangles(&rows, "window".parse().unwrap()), Ok(1));
+        assert_eq!(count_triangles(&rows[..2], Grouping::Window), Ok(0));
+        assert!("spiral".parse::<Grouping>().is_err());
+    }
+
+    #[test]
+    fn test_count_from_reader() {
+        let input = "5 10 25\n3 4 5\n\n6 8 10\n1 1 1\n".repeat(999);
+
+        assert_eq!(count_part1(input.as_bytes()).unwrap(), 2997);
+        assert_eq!(count_part2(input.as_bytes()).unwrap(), 666);
+
+        match count_part2("3 4 5\n3 4\n".as_bytes()) {
+            Err(Error::Parse { line, .. }) => assert_eq!(line, 2),
+            x => panic!("Expected Parse, got {:?}", x),
+        }
+
+        match count_from("3 4 5\n\n3 4 5\n3 4 5\n\n6 8 10\n".as_bytes(), Grouping::Columns) {
+            Err(e) => assert_eq!(e.to_string(), "6:1: 1 row(s) from row 4 don't make a whole group of 3 (in \"6 8 10\")"),
+            x => panic!("Expected Parse, got {:?}", x),
+        }
+
+        let input = "3 4 5\n\n3 4 5\n3 4 5\n\n6 8 10\n7 7 7\n";
+        let rows = Day03::parse(input).unwrap();
+
+        assert_eq!(Day03::part1(&rows), Answer::Number(5));
+        assert_eq!(Day03::part2(&rows), Answer::Unsolved);
+
+        match count_part2(input.as_bytes()) {
+            Err(e) => assert_eq!(e.to_string(), "6:1: 2 row(s) from row 4 don't make a whole group of 3 (in \"6 8 10\")"),
+            x => panic!("Expected Parse, got {:?}", x),
+        }
+    }
+}
\ No newline at end of file
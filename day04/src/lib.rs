@@ -0,0 +1,185 @@
+use common::Error;
+use common::counter::Counter;
+use common::parse::{self, LineError};
+use common::solution::{Answer, Solution};
+use regex::Regex;
+use std::str::FromStr;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Room>;
+
+    fn parse(input: &str) -> Result<Vec<Room>, Error> {
+        parse::parse_lines(input)
+    }
+
+    fn part1(rooms: &Vec<Room>) -> Answer {
+        solve_part1(rooms).into()
+    }
+
+    fn part2(rooms: &Vec<Room>) -> Answer {
+        solve_part2(rooms).into()
+    }
+}
+
+pub fn solve_part1(rooms: &[Room]) -> u32 {
+    rooms.iter()
+        .filter(|r| r.is_real())
+        .map(|r| r.sector_id)
+        .sum()
+}
+
+pub fn solve_part2(rooms: &[Room]) -> Option<u32> {
+    rooms.iter()
+        .filter(|r| r.is_real())
+        .find(|r| r.decrypted_name == "northpole object storage")
+        .map(|r| r.sector_id)
+}
+
+#[derive(Debug)]
+pub struct Room {
+    name: String,
+    decrypted_name: String,
+    sector_id: u32,
+    checksum: String,
+}
+
+pub fn decrypt_name(name: &str, sector_id: u32) -> String {
+    name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_lowercase() {
+                (b'a' + (c as u8 + (sector_id % 26) as u8 - b'a') % 26) as char
+            } else if c == '-' {
+                ' '
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+/// The inverse of `decrypt_name`: shifts letters back by the sector ID and
+/// turns spaces into dashes. Only lowercase words separated by single spaces
+/// can be decrypted back, so anything else is rejected.
+pub fn encrypt_name(plaintext: &str, sector_id: u32) -> Result<String, String> {
+    let valid = plaintext.split(' ')
+        .all(|word| !word.is_empty() && word.chars().all(|c| c.is_ascii_lowercase()));
+
+    if !valid {
+        return Err(format!("Invalid room name \"{}\": expected lowercase words separated by single spaces.", plaintext));
+    }
+
+    Ok(plaintext
+        .chars()
+        .map(|c| {
+            if c.is_ascii_lowercase() {
+                (b'a' + (c as u8 - b'a' + 26 - (sector_id % 26) as u8) % 26) as char
+            } else if c == ' ' {
+                '-'
+            } else {
+                c
+            }
+        })
+        .collect())
+}
+
+/// The five most common letters in `name`, ties broken alphabetically.
+/// Anything but lowercase letters is ignored.
+pub fn checksum(name: &str) -> String {
+    let frequencies: Counter<char> = name.chars().filter(|c| c.is_ascii_lowercase()).collect();
+
+    frequencies.most_common(5).into_iter()
+        .map(|(&c, _)| c)
+        .collect()
+}
+
+/// Encrypts `plaintext` into a real room line like `name-123[check]`.
+pub fn encrypt_room(plaintext: &str, sector_id: u32) -> Result<String, String> {
+    let name = encrypt_name(plaintext, sector_id)?;
+
+    Ok(format!("{}-{}[{}]", name, sector_id, checksum(&name)))
+}
+
+impl FromStr for Room {
+    type Err = LineError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"^(.*)-([0-9]+)\[(.*)]$").unwrap();
+
+        let caps = re.captures(line)
+            .ok_or_else(|| LineError::from("Expected a room like name-123[check]".to_string()))?;
+
+        let name = caps.get(1).unwrap().as_str();
+        let sector = caps.get(2).unwrap();
+        let sector_id = sector.as_str().parse::<u32>()
+            .map_err(|_| LineError::new(sector.start() + 1, format!("Invalid sector ID: {}", sector.as_str())))?;
+        let checksum = caps.get(3).unwrap().as_str().to_string();
+        let decrypted_name = decrypt_name(name, sector_id);
+
+        Ok(Room {
+            name: name.replace('-', ""),
+            decrypted_name,
+            sector_id,
+            checksum,
+        })
+    }
+}
+
+impl Room {
+    pub fn is_real(&self) -> bool {
+        self.checksum == checksum(&self.name)
+    }
+
+    pub fn sector_id(&self) -> u32 {
+        self.sector_id
+    }
+
+    pub fn decrypted_name(&self) -> &str {
+        &self.decrypted_name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_room() {
+        assert!("aaaaa-bbb-z-y-x-123[abxyz]".parse::<Room>().unwrap().is_real());
+        assert!("a-b-c-d-e-f-g-h-987[abcde]".parse::<Room>().unwrap().is_real());
+        assert!("not-a-real-room-404[oarel]".parse::<Room>().unwrap().is_real());
+        assert!(!"totally-real-room-200[decoy]".parse::<Room>().unwrap().is_real());
+        assert!(!"aaaaa-bbb-z-y-x-123[xyz]".parse::<Room>().unwrap().is_real());
+        assert!("totally-real-room[decoy]".parse::<Room>().is_err());
+    }
+
+    #[test]
+    fn test_decrypt() {
+        assert_eq!(decrypt_name("qzmt-zixmtkozy-ivhz", 343), "very encrypted name");
+    }
+
+    #[test]
+    fn test_encrypt() {
+        assert_eq!(encrypt_name("very encrypted name", 343), Ok("qzmt-zixmtkozy-ivhz".to_string()));
+        assert_eq!(checksum("aaaaa-bbb-z-y-x"), "abxyz");
+        assert_eq!(checksum("ab-a 42x"), "abx");
+        assert_eq!(encrypt_room("very encrypted name", 343), Ok("qzmt-zixmtkozy-ivhz-343[zimth]".to_string()));
+
+        for name in &["North", "room-a", "room a 42x", "two  spaces", " lead", "trail ", ""] {
+            assert!(encrypt_room(name, 3).is_err(), "{:?}", name);
+        }
+
+        assert!(!"North-1[abc]".parse::<Room>().unwrap().is_real());
+
+        for (name, sector_id) in &[("northpole object storage", 0), ("northpole object storage", 991), ("a", 25)] {
+            let room = encrypt_room(name, *sector_id).unwrap().parse::<Room>().unwrap();
+
+            assert!(room.is_real());
+            assert_eq!((room.decrypted_name(), room.sector_id()), (*name, *sector_id));
+        }
+    }
+}
\ No newline at end of file
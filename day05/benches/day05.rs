@@ -0,0 +1,21 @@
+use common::files;
+use common::solution::{self, Solution};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day05::Day05;
+
+fn bench(c: &mut Criterion) {
+    let raw = files::get_file_as_string(&solution::input_file_name(Day05::DAY));
+    let input = Day05::parse(&raw).unwrap();
+
+    c.bench_function("day05 parse", |b| b.iter(|| Day05::parse(&raw)));
+    c.bench_function("day05 part1", |b| b.iter(|| Day05::part1(&input)));
+    c.bench_function("day05 part2", |b| b.iter(|| Day05::part2(&input)));
+}
+
+// Each part hashes millions of candidates, so keep to criterion's minimum sample size.
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = bench
+}
+criterion_main!(benches);
@@ -0,0 +1,98 @@
+use common::Error;
+use common::counter::ColumnCounter;
+use common::solution::{Answer, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, Error> {
+        Ok(input.lines().map(|l| l.to_string()).collect())
+    }
+
+    fn part1(lines: &Vec<String>) -> Answer {
+        solve_part1(lines).into()
+    }
+
+    fn part2(lines: &Vec<String>) -> Answer {
+        solve_part2(lines).into()
+    }
+}
+
+pub fn solve_part1(lines: &[String]) -> String {
+    build_column_counter(lines).columns().iter()
+        .map(|counter| *counter.most_common(1)[0].0)
+        .collect()
+}
+
+pub fn solve_part2(lines: &[String]) -> String {
+    build_column_counter(lines).columns().iter()
+        .map(|counter| *counter.least_common(1)[0].0)
+        .collect()
+}
+
+pub fn build_column_counter(lines: &[String]) -> ColumnCounter<char> {
+    let mut columns = ColumnCounter::new();
+
+    for line in lines {
+        columns.add_row(line.chars());
+    }
+
+    columns
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_part1() {
+        let lines = vec![
+            "eedadn".to_string(),
+            "drvtee".to_string(),
+            "eandsr".to_string(),
+            "raavrd".to_string(),
+            "atevrs".to_string(),
+            "tsrnev".to_string(),
+            "sdttsa".to_string(),
+            "rasrtv".to_string(),
+            "nssdts".to_string(),
+            "ntnada".to_string(),
+            "svetve".to_string(),
+            "tesnvt".to_string(),
+            "vntsnd".to_string(),
+            "vrdear".to_string(),
+            "dvrsen".to_string(),
+            "enarar".to_string(),
+        ];
+
+        assert_eq!(solve_part1(&lines), "easter".to_string());
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let lines = vec![
+            "eedadn".to_string(),
+            "drvtee".to_string(),
+            "eandsr".to_string(),
+            "raavrd".to_string(),
+            "atevrs".to_string(),
+            "tsrnev".to_string(),
+            "sdttsa".to_string(),
+            "rasrtv".to_string(),
+            "nssdts".to_string(),
+            "ntnada".to_string(),
+            "svetve".to_string(),
+            "tesnvt".to_string(),
+            "vntsnd".to_string(),
+            "vrdear".to_string(),
+            "dvrsen".to_string(),
+            "enarar".to_string(),
+        ];
+
+        assert_eq!(solve_part2(&lines), "advent".to_string());
+    }
+}
\ No newline at end of file
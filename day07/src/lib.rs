@@ -0,0 +1,147 @@
+use common::Error;
+use common::parse::{self, LineError};
+use common::solution::{Answer, Solution};
+use regex::Regex;
+use std::str::FromStr;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<IpAddress>;
+
+    fn parse(input: &str) -> Result<Vec<IpAddress>, Error> {
+        parse::parse_lines(input)
+    }
+
+    fn part1(ip_addresses: &Vec<IpAddress>) -> Answer {
+        solve_part1(ip_addresses).into()
+    }
+
+    fn part2(ip_addresses: &Vec<IpAddress>) -> Answer {
+        solve_part2(ip_addresses).into()
+    }
+}
+
+pub fn solve_part1(ip_addresses: &[IpAddress]) -> usize {
+    ip_addresses.iter()
+        .filter(|ip| ip.supports_tls())
+        .count()
+}
+
+pub fn solve_part2(ip_addresses: &[IpAddress]) -> usize {
+    ip_addresses.iter()
+        .filter(|ip| ip.supports_ssl())
+        .count()
+}
+
+#[derive(Debug)]
+pub struct IpAddress {
+    full: String,
+    supernet_sequences: Vec<String>,
+    hypernet_sequences: Vec<String>,
+}
+
+impl FromStr for IpAddress {
+    type Err = LineError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        if let Some(i) = line.chars().position(|c| !(c.is_ascii_lowercase() || c == '[' || c == ']')) {
+            return Err(LineError::new(i + 1, format!("Invalid character in IP address: {:?}", line.chars().nth(i).unwrap())));
+        }
+
+        let re = Regex::new(r"\[([a-z]+)]").unwrap();
+
+        let supernet_sequences: Vec<String> = re.split(line)
+            .map(|s| s.to_string())
+            .collect();
+
+        let mut hypernet_sequences = Vec::new();
+
+        let mut stripped = line.to_string();
+        while let Some(caps) = re.captures(&stripped) {
+            hypernet_sequences.push(caps.get(1).unwrap().as_str().to_string());
+            stripped = stripped.replace(caps.get(0).unwrap().as_str(), "");
+        }
+
+        Ok(IpAddress {
+            full: line.to_string(),
+            supernet_sequences,
+            hypernet_sequences,
+        })
+    }
+}
+
+impl IpAddress {
+
+    pub fn full(&self) -> &str {
+        &self.full
+    }
+
+    pub fn supports_ssl(&self) -> bool {
+        let abas = get_abas(&self.supernet_sequences);
+
+        let hypernet_contains_bab = abas.iter()
+            .any(|aba| {
+                let mut bab = aba[1..].to_string();
+                bab.push(aba.chars().nth(1).unwrap());
+
+                self.hypernet_sequences.iter()
+                    .any(|s| s.contains(&bab))
+            });
+
+        !abas.is_empty() && hypernet_contains_bab
+    }
+
+    pub fn supports_tls(&self) -> bool {
+        contains_abba(&self.supernet_sequences) && !contains_abba(&self.hypernet_sequences)
+    }
+}
+
+fn contains_abba(strings: &[String]) -> bool {
+    for s in strings.iter() {
+        for bytes in s.as_bytes().windows(4) {
+            if bytes[0] == bytes[3] && bytes[1] == bytes[2] && bytes[0] != bytes[1] {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+fn get_abas(strings: &[String]) -> Vec<String> {
+    let mut results = Vec::new();
+
+    for s in strings.iter() {
+        for bytes in s.as_bytes().windows(3) {
+            if bytes[0] == bytes[2] && bytes[0] != bytes[1] {
+                results.push(String::from_utf8_lossy(bytes).to_string());
+            }
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supports_tls() {
+        assert!("abba[mnop]qrst".parse::<IpAddress>().unwrap().supports_tls());
+        assert!(!"abcd[bddb]xyyx".parse::<IpAddress>().unwrap().supports_tls());
+        assert!(!"aaaa[qwer]tyui".parse::<IpAddress>().unwrap().supports_tls());
+        assert!("ioxxoj[asdfgh]zxcvbn".parse::<IpAddress>().unwrap().supports_tls());
+    }
+
+    #[test]
+    fn test_supports_ssl() {
+        assert!("aba[bab]xyz".parse::<IpAddress>().unwrap().supports_ssl());
+        assert!(!"xyx[xyx]xyx".parse::<IpAddress>().unwrap().supports_ssl());
+        assert!("aaa[kek]eke".parse::<IpAddress>().unwrap().supports_ssl());
+        assert!("zazbz[bzb]cdb".parse::<IpAddress>().unwrap().supports_ssl());
+    }
+}
\ No newline at end of file
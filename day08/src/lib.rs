@@ -0,0 +1,204 @@
+use common::Error;
+use common::grid::{Grid, Point};
+use common::parse;
+use common::solution::{Answer, Solution};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, Error> {
+        parse::parse_lines(input)
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Answer {
+        run_instructions(instructions).lit_count().into()
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Answer {
+        Answer::Grid(run_instructions(instructions).pixels().clone())
+    }
+}
+
+pub fn run_instructions(instructions: &[Instruction]) -> Screen {
+    let mut screen = Screen::new(50, 6);
+
+    for i in instructions {
+        screen.apply_instruction(i);
+    }
+
+    screen
+}
+
+#[derive(Debug)]
+pub struct Screen {
+    pixels: Grid<bool>,
+}
+
+impl Screen {
+    pub fn new(width: usize, height: usize) -> Self {
+        Screen {
+            pixels: Grid::new(width, height, false),
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.pixels[Point::new(x as i64, y as i64)]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        self.pixels[Point::new(x as i64, y as i64)] = value;
+    }
+
+    pub fn pixels(&self) -> &Grid<bool> {
+        &self.pixels
+    }
+
+    pub fn lit_count(&self) -> usize {
+        self.pixels.iter()
+            .filter(|&p| p == &true)
+            .count()
+    }
+
+    /// Applies `i`, ignoring any part of it that falls outside the screen.
+    pub fn apply_instruction(&mut self, i: &Instruction) {
+        match i {
+            Instruction::Rect { width, height } => {
+                for x in 0..*width.min(&self.pixels.width()) {
+                    for y in 0..*height.min(&self.pixels.height()) {
+                        self.set(x, y, true);
+                    }
+                }
+            },
+            Instruction::RotateRow { index, distance } => {
+                if *index < self.pixels.height() {
+                    self.pixels.rotate_row(*index, *distance);
+                }
+            },
+            Instruction::RotateColumn { index, distance } => {
+                if *index < self.pixels.width() {
+                    self.pixels.rotate_column(*index, *distance);
+                }
+            },
+        }
+    }
+}
+
+impl Display for Screen {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let rendered = self.pixels.map(|&p| match p {
+            true => "# ",
+            false => ". ",
+        });
+
+        write!(f, "{}", rendered)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Instruction {
+    Rect { width: usize, height: usize },
+    RotateRow { index: usize, distance: usize },
+    RotateColumn { index: usize, distance: usize },
+}
+
+fn parse_number(s: Option<&str>) -> Result<usize, String> {
+    match s {
+        Some(s) => s.parse::<usize>().map_err(|_| format!("Invalid number \"{}\" specified.", s)),
+        None => Err("Missing number.".to_string()),
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let split: Vec<&str> = line.split(' ').collect();
+
+        match split[0] {
+            "rect" => {
+                let mut dimensions_split = split.get(1).unwrap_or(&"").split('x');
+
+                Ok(Instruction::Rect {
+                    width: parse_number(dimensions_split.next())?,
+                    height: parse_number(dimensions_split.next())?,
+                })
+            },
+            "rotate" => {
+                let index = parse_number(split.get(2).and_then(|s| s.split('=').nth(1)))?;
+                let distance = parse_number(split.get(4).cloned())?;
+
+                match split.get(1) {
+                    Some(&"row") => {
+                        Ok(Instruction::RotateRow {
+                            index,
+                            distance,
+                        })
+                    },
+                    Some(&"column") => {
+                        Ok(Instruction::RotateColumn {
+                            index,
+                            distance,
+                        })
+                    },
+                    x => Err(format!("Invalid rotation type \"{}\" specified.", x.unwrap_or(&""))),
+                }
+            },
+            x => Err(format!("Invalid instruction \"{}\" specified.", x)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Instruction::{Rect, RotateRow, RotateColumn};
+
+    #[test]
+    fn test_instruction_from_str() {
+        assert_eq!(
+            "rect 4x1".parse::<Instruction>().unwrap(),
+            Rect { width: 4, height: 1 }
+        );
+        assert_eq!(
+            "rotate row y=0 by 5".parse::<Instruction>().unwrap(),
+            RotateRow { index: 0, distance: 5 }
+        );
+        assert_eq!(
+            "rotate column x=30 by 1".parse::<Instruction>().unwrap(),
+            RotateColumn { index: 30, distance: 1 }
+        );
+        assert!("rotate diagonal x=1 by 1".parse::<Instruction>().is_err());
+        assert!("rect 4y1".parse::<Instruction>().is_err());
+    }
+
+    #[test]
+    fn test_apply_instruction() {
+        let mut screen = Screen::new(7, 3);
+
+        for line in &["rect 3x2", "rotate column x=1 by 1", "rotate row y=0 by 4", "rotate column x=1 by 1"] {
+            screen.apply_instruction(&line.parse::<Instruction>().unwrap());
+        }
+
+        assert_eq!(screen.to_string(), "\
+            . # . . # . # \n\
+            # . # . . . . \n\
+            . # . . . . . \n");
+        assert_eq!(screen.lit_count(), 6);
+
+        let before = screen.to_string();
+        for line in &["rotate row y=10 by 1", "rotate column x=7 by 2"] {
+            screen.apply_instruction(&line.parse::<Instruction>().unwrap());
+        }
+
+        assert_eq!(screen.to_string(), before);
+
+        screen.apply_instruction(&"rect 100x100".parse::<Instruction>().unwrap());
+        assert_eq!(screen.lit_count(), 21);
+    }
+}
@@ -0,0 +1,18 @@
+use common::files;
+use common::solution::{self, Solution};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day09::Day09;
+
+fn bench(c: &mut Criterion) {
+    let raw = files::get_file_as_string(&solution::input_file_name(Day09::DAY));
+    let input = Day09::parse(&raw).unwrap();
+
+    c.bench_function("day09 parse", |b| b.iter(|| Day09::parse(&raw)));
+    c.bench_function("day09 part1", |b| b.iter(|| Day09::part1(&input)));
+    // The real input expands to gigabytes with decompress_v2, so use the largest puzzle example.
+    let example = Day09::parse("(27x12)(20x12)(13x14)(7x10)(1x12)A").unwrap();
+    c.bench_function("day09 part2", |b| b.iter(|| Day09::part2(&example)));
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);